@@ -1,11 +1,12 @@
 from socha.api.networking.player_client import IClientHandler
 from socha.api.plugin.penguins import *
 from socha.api.protocol.protocol import Result
-from socha.starter import Starter
+from socha.starter import Starter, SochaConnectionError
 
 __all__ = [
     'IClientHandler',
     'Starter',
+    'SochaConnectionError',
     'Vector',
     'CartesianCoordinate',
     'HexCoordinate',
@@ -13,7 +14,14 @@ __all__ = [
     'Team',
     'Field',
     'Board',
+    'BoardStats',
+    'UndoToken',
     'Fishes',
+    'GameOverError',
+    'GamePhase',
     'GameState',
+    'MoveIterator',
+    'OpeningBook',
+    'batch_possible_moves',
     'Result',
 ]
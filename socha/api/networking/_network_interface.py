@@ -4,7 +4,7 @@ Handels the tcp connection to the server.
 import logging
 import re
 import socket
-from typing import Union
+from typing import Optional
 
 
 class _NetworkInterface:
@@ -12,15 +12,26 @@ class _NetworkInterface:
     This interface handels all package transfers. It'll _send and _receive data from a given connection.
     """
 
-    def __init__(self, host="localhost", port=13050, timeout=10):
+    # the opening or closing tag of an element, with groups for the slash of closing tags, the name
+    # and the slash of self-closing tags
+    _tag_regex = re.compile(br"<(/?)([^\s/<>]+)[^<>]*?(/?)>")
+
+    def __init__(self, host="localhost", port=13050, timeout=10, keep_alive: int = None, record_file: str = None,
+                 buffer_size: int = 16129):
         """
         :param host: Host of the server. Default is localhost.
         :param port: Port of the server. Default is 13050.
         :param timeout: Timeout for receiving data from the server. Default are 10 seconds.
+        :param keep_alive: Seconds of idling, after which the connection is kept alive. Default is disabled.
+        :param record_file: File to which all received data is appended for replaying it later. Default is disabled.
+        :param buffer_size: Maximum amount of bytes that are read at once. Default are 16129 bytes.
         """
         self.host = host
         self.port = port
         self.timeout = timeout
+        self.keep_alive = keep_alive
+        self.record_file = record_file
+        self.buffer_size = buffer_size
         self.connected: bool = False
         self.socket = None
 
@@ -32,10 +43,26 @@ class _NetworkInterface:
         """
         self.socket = socket.socket(socket.AF_INET, socket.SOCK_STREAM)
         self.socket.settimeout(self.timeout)
+        if self.keep_alive:
+            self._enable_keep_alive()
         self.socket.connect((self.host, self.port))
         self.connected = True
         logging.info("Connected to server.")
 
+    def _enable_keep_alive(self):
+        """
+        Lets the operating system send TCP keep-alive probes, if the connection has been idle for a while.
+        The probes contain no payload, so they don't interfere with the protocol.
+        """
+        self.socket.setsockopt(socket.SOL_SOCKET, socket.SO_KEEPALIVE, 1)
+        if hasattr(socket, "TCP_KEEPIDLE"):
+            self.socket.setsockopt(socket.IPPROTO_TCP, socket.TCP_KEEPIDLE, self.keep_alive)
+        elif hasattr(socket, "TCP_KEEPALIVE"):
+            self.socket.setsockopt(socket.IPPROTO_TCP, socket.TCP_KEEPALIVE, self.keep_alive)
+        if hasattr(socket, "TCP_KEEPINTVL"):
+            self.socket.setsockopt(socket.IPPROTO_TCP, socket.TCP_KEEPINTVL, self.keep_alive)
+        logging.debug("Enabled keep-alive after %s seconds of idling.", self.keep_alive)
+
     def close(self):
         """
         Closes the connection to the server.
@@ -59,7 +86,7 @@ class _NetworkInterface:
         :return: A package in bytes, None if there where no packages.
         """
         try:
-            data = self.socket.recv(16129)
+            data = self.socket.recv(self.buffer_size)
             # print(data.decode("utf-8"))
             return data
         except socket.timeout:
@@ -70,23 +97,103 @@ class _NetworkInterface:
 
     def receive(self) -> bytes:
         """
-        Appends all incoming packages into one and tries to find any protocol related data.
-        :return: The protocol object.
+        Appends all incoming packages into one, until it contains a complete protocol element.
+        A package can contain several elements or only a part of one, e.g. of a large memento.
+        :return: The protocol element, that has been received first.
         """
-        room_regex = re.compile(br"<room[\s\S]+?</room>")
-        tag_regex = re.compile(br"<.*/>")
-        chunk = b""
         while True:
-            if chunk or self.buffer:
-                self.buffer += chunk
-                if room_regex.search(self.buffer):
-                    receive = room_regex.search(self.buffer).group()
-                    self.buffer = self.buffer.replace(receive, b"")
-                    logging.debug("Received data:\n %s", receive.decode("utf-8"))
-                    return receive
-                if tag_regex.search(self.buffer):
-                    receive = tag_regex.search(self.buffer).group()
-                    self.buffer = self.buffer.replace(receive, b"")
-                    logging.debug("Received data:\n %s", receive.decode("utf-8"))
-                    return receive
+            receive = self._next_element()
+            if receive:
+                logging.debug("Received data:\n %s", receive.decode("utf-8"))
+                self._record(receive)
+                return receive
             chunk = self.receive_socket_data()
+            if chunk:
+                self.buffer += chunk
+
+    def _next_element(self) -> Optional[bytes]:
+        """
+        Cuts the first complete top-level element out of the buffer.
+        The tags of the protocol element, that encloses all other elements, are skipped, as well as xml declarations.
+        :return: The element, or None if the buffer doesn't contain a complete element yet.
+        """
+        while True:
+            start = self.buffer.find(b"<")
+            if start == -1:
+                return None
+            tag = self._tag_regex.match(self.buffer, start)
+            if not tag:
+                return None
+            closing, name, self_closing = tag.groups()
+            if closing or name == b"protocol" or name.startswith((b"?", b"!")):
+                self.buffer = self.buffer[tag.end():]
+                continue
+            if self_closing:
+                end = tag.end()
+            else:
+                closing_tag = b"</" + name + b">"
+                end = self.buffer.find(closing_tag, tag.end())
+                if end == -1:
+                    return None
+                end += len(closing_tag)
+            element = self.buffer[start:end]
+            self.buffer = self.buffer[end:]
+            return element
+
+    def _record(self, data: bytes):
+        """
+        Appends the received data to the record file, if recording is enabled.
+        """
+        if self.record_file:
+            with open(self.record_file, "ab") as file:
+                file.write(data + b"\n")
+
+
+class _ReplayInterface(_NetworkInterface):
+    """
+    This interface replays a recorded protocol log instead of connecting to a server.
+    The recorded data is received in the same way, as if it came from a server, and all sent data is only logged.
+    """
+
+    def __init__(self, replay_file: str, buffer_size: int = 16129):
+        """
+        :param replay_file: The file that contains the recorded protocol log.
+        :param buffer_size: Maximum amount of bytes that are read at once. Default are 16129 bytes.
+        """
+        super().__init__(buffer_size=buffer_size)
+        self.replay_file = replay_file
+        self.file = None
+
+    def connect(self):
+        """
+        Opens the recorded protocol log.
+        """
+        self.file = open(self.replay_file, "rb")
+        self.connected = True
+        logging.info("Replaying %s.", self.replay_file)
+
+    def close(self):
+        """
+        Closes the recorded protocol log.
+        """
+        self.file.close()
+        self.connected = False
+        logging.info("Closed replay.")
+
+    def send(self, data: bytes):
+        """
+        There is no server to send to, so the data is only logged.
+        :param data: The data that would have been sent.
+        """
+        logging.debug("Not sent while replaying:\n %s", data.decode("utf-8"))
+
+    def receive_socket_data(self) -> bytes:
+        """
+        Reads the next package from the recorded protocol log.
+        :return: A package in bytes.
+        :raise EOFError: If the end of the recorded protocol log has been reached.
+        """
+        data = self.file.read(self.buffer_size)
+        if not data:
+            raise EOFError("Reached the end of the replay.")
+        return data
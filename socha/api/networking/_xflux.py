@@ -10,7 +10,7 @@ from xsdata.formats.dataclass.parsers.handlers import XmlEventHandler
 from xsdata.formats.dataclass.serializers import XmlSerializer
 from xsdata.formats.dataclass.serializers.config import SerializerConfig
 
-from socha.api.networking._network_interface import _NetworkInterface
+from socha.api.networking._network_interface import _NetworkInterface, _ReplayInterface
 from socha.api.plugin.penguins import Move
 from socha.api.protocol.protocol import *
 
@@ -41,6 +41,22 @@ def customClassFactory(clazz, params: dict):
     return clazz(**params)
 
 
+def _convertMoveToData(move: Move) -> Data:
+    """
+    Converts a Move to the data element the server expects.
+    Placements only contain the destination, while sliding moves contain the origin and the destination.
+    Both are given in the same hex coordinates, the server uses.
+
+    :param move: The move to convert.
+    :return: The data element of class move.
+    """
+    from_value = None
+    if move.from_value:
+        from_value = From(x=move.from_value.x, y=move.from_value.y)
+    to_value = To(x=move.to_value.x, y=move.to_value.y)
+    return Data(class_value="move", from_value=from_value, to=to_value)
+
+
 class _XFlux:
     """
     Serialize and deserialize objects to and from XML.
@@ -70,10 +86,7 @@ class _XFlux:
         :return: The serialized byte stream.
         """
         if isinstance(object_class, Move):
-            from_value = From(x=object_class.from_value.x, y=object_class.from_value.y)
-            to_value = To(x=object_class.to_value.x, y=object_class.to_value.y)
-            data = Data(class_value="move", from_value=from_value, to=to_value)
-            return self.serializer.render(data).encode("utf-8")
+            return self.serializer.render(_convertMoveToData(object_class)).encode("utf-8")
 
         return self.serializer.render(object_class).encode("utf-8")
 
@@ -83,12 +96,21 @@ class _XFluxClient:
     Streams data from and to the server.
     """
 
-    def __init__(self, host: str, port: int):
+    def __init__(self, host: str, port: int, keep_alive: int = None, record_file: str = None,
+                 replay_file: str = None, buffer_size: int = 16129):
         """
         :param host: Host of the server.
         :param port: Port of the server.
+        :param keep_alive: Seconds of idling, after which the connection is kept alive. None disables it.
+        :param record_file: File to which all received data is recorded. None disables it.
+        :param replay_file: Recorded protocol log, that is replayed instead of connecting to the server.
+        :param buffer_size: Maximum amount of bytes that are read at once.
         """
-        self._network_interface = _NetworkInterface(host, port)
+        if replay_file:
+            self._network_interface = _ReplayInterface(replay_file, buffer_size=buffer_size)
+        else:
+            self._network_interface = _NetworkInterface(host, port, keep_alive=keep_alive, record_file=record_file,
+                                                        buffer_size=buffer_size)
         self.connect_to_server()
         self._x_flux = _XFlux()
         self._running = False
@@ -103,6 +125,10 @@ class _XFluxClient:
             receiving = self._network_interface.receive()
             cls = self._x_flux.deserialize_object(receiving)
             return cls
+        except EOFError as e:
+            logging.info(e)
+            self._network_interface.close()
+            self._running = False
         except OSError:
             logging.error("Shutting down abnormally...")
             self._running = False
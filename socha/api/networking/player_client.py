@@ -1,16 +1,19 @@
 """
 This module handels the communication with the api and the students logic.
 """
+import json
 import logging
+import os
 import sys
 import time
-from typing import List, Union
+from typing import List, Union, Optional
 
-from socha.api.networking._xflux import _XFluxClient
+from socha.api.networking._xflux import _XFluxClient, _convertMoveToData
 from socha.api.plugin import penguins
-from socha.api.plugin.penguins import Field, GameState, Move, CartesianCoordinate
-from socha.api.protocol.protocol import State, Board, Data, \
-    Error, From, Join, Joined, JoinPrepared, JoinRoom, To, Team, Room, Result, MoveRequest, ObservableRoomMessage, Left
+from socha.api.plugin.penguins import Field, GameState, Move, CartesianCoordinate, HexCoordinate
+from socha.api.protocol.protocol import State, Board, \
+    Error, Join, Joined, JoinPrepared, JoinRoom, Team, Room, Result, MoveRequest, ObservableRoomMessage, Left, \
+    LastMove, WelcomeMessage
 from socha.api.protocol.protocol_packet import ProtocolPacket
 
 
@@ -29,12 +32,35 @@ def _convertBoard(protocolBoard: Board) -> penguins.Board:
     return penguins.Board(boardList)
 
 
+def _convertMove(protocolMove: Optional[LastMove]) -> Optional[Move]:
+    """
+    Converts a protocol LastMove to a Move of the plugin.
+    :return: The move, or None if no move was made yet.
+    """
+    if not protocolMove:
+        return None
+    from_value = None
+    if protocolMove.from_value:
+        from_value = HexCoordinate(x=protocolMove.from_value.x, y=protocolMove.from_value.y)
+    return Move(from_value=from_value, to_value=HexCoordinate(x=protocolMove.to.x, y=protocolMove.to.y))
+
+
+def _convertState(protocolState: State) -> GameState:
+    """
+    Converts a protocol State, that the server sends within a memento, to a GameState for using in the logic.
+    """
+    return GameState(turn=protocolState.turn, start_team=Team(protocolState.start_team),
+                     board=_convertBoard(protocolState.board), last_move=_convertMove(protocolState.last_move),
+                     fishes=penguins.Fishes(protocolState.fishes.int_value[0], protocolState.fishes.int_value[1]))
+
+
 class IClientHandler:
     history: List[Union[GameState, Error, Result]] = []
 
     def calculate_move(self) -> Move:
         """
         Calculates a move that the logic wants the server to perform in the game room.
+        It is not called, if the team of the client has no possible moves in the last state.
         """
 
     def on_update(self, state: GameState):
@@ -47,10 +73,20 @@ class IClientHandler:
         """
         If the game has ended the server will _send a result message.
         This method will called if this happens.
+        The result tells the winner with `get_winner`, the final fish of each team with `get_fishes`
+        and why the game has ended with `get_cause`.
 
         :param roomMessage: The Result the server has sent.
         """
 
+    def on_welcome_message(self, message: WelcomeMessage):
+        """
+        If the client has joined a game room, the server sends a welcome message,
+        that tells the client which team it is playing.
+
+        :param message: The WelcomeMessage the server has sent, containing the team of this client.
+        """
+
     def on_error(self, logMessage: str):
         """
         If error occurs,
@@ -108,15 +144,60 @@ class IClientHandler:
         """
 
 
+def _dry_run(handler: IClientHandler, state: GameState) -> Move:
+    """
+    Tests the logic without a server.
+    The given state is passed to the logic and the calculated move is checked against the rules.
+
+    :param handler: The logic to test.
+    :param state: The state the logic has to calculate a move for.
+    :return: The valid move the logic has calculated.
+    :raise ValueError: If the logic has calculated an invalid move.
+    """
+    handler.on_update(state)
+    move = handler.calculate_move()
+    if not isinstance(move, Move) or not state.is_valid_move(move):
+        raise ValueError(f"The logic calculated the invalid move {move} for team {state.current_team} "
+                         f"in turn {state.turn}.")
+    logging.info(f"The logic calculated the valid move {move}.")
+    return move
+
+
+def _read_resume_token(path: str) -> Optional[str]:
+    """
+    Reads the room, that a previous run of the client has stored to rejoin its game after a crash.
+
+    :param path: The file of the resume token.
+    :return: The id of the room, or None if there is no readable token.
+    """
+    try:
+        with open(path) as file:
+            return json.load(file).get("room_id")
+    except (OSError, ValueError, AttributeError):
+        return None
+
+
 class _PlayerClient(_XFluxClient):
     """
     The PlayerClient handles all incoming and outgoing objects accordingly to their types.
     """
 
-    def __init__(self, host: str, port: int, handler: IClientHandler, survive: bool):
-        super().__init__(host, port)
+    def __init__(self, host: str, port: int, handler: IClientHandler, survive: bool, keep_alive: int = None,
+                 record_file: str = None, replay_file: str = None, requested_color: Team = None,
+                 buffer_size: int = 16129, resume_token_path: str = None, strict: bool = False):
+        super().__init__(host, port, keep_alive, record_file, replay_file, buffer_size)
         self._game_handler = handler
         self.survive = survive
+        self.requested_color = requested_color
+        self.resume_token_path = resume_token_path
+        self.strict = strict
+        self.team: Optional[Team] = None
+        self.game_state: Optional[GameState] = None
+        self.room_id: Optional[str] = None
+        self._rejoining = False
+        if requested_color:
+            logging.info(f"Requested to play as team {requested_color}, but the server assigns the teams. "
+                         f"The client will play as whatever team it is assigned.")
 
     def join_game(self):
         super()._send(Join())
@@ -124,6 +205,14 @@ class _PlayerClient(_XFluxClient):
     def join_game_room(self, room_id: str):
         super()._send(JoinRoom(room_id=room_id))
 
+    def rejoin_game_room(self, room_id: str):
+        """
+        Joins the room of the resume token. If the server doesn't let the client join it,
+        the token is removed and the client joins any open game instead.
+        """
+        self._rejoining = True
+        self.join_game_room(room_id)
+
     def join_game_with_reservation(self, reservation: str):
         super()._send(JoinPrepared(reservation_code=reservation))
 
@@ -132,40 +221,118 @@ class _PlayerClient(_XFluxClient):
 
     def _on_object(self, message):
         if isinstance(message, Room):
-            room_id: str = message.room_id
+            room_id: str = message.room_id or self.room_id
             data = message.data.class_binding
-            if isinstance(data, MoveRequest):
+            if isinstance(data, MoveRequest) and self._is_blocked():
+                logging.error(f"The server requested a move of team {self.team or self.game_state.current_team}, "
+                              f"which has no possible moves in the last received state of turn "
+                              f"{self.game_state.turn}. The state of the client is out of sync with the server, "
+                              f"so the logic is not asked for a move.")
+            elif isinstance(data, MoveRequest):
                 start_time = time.time()
                 response = self._game_handler.calculate_move()
                 logging.info(f"Sent {response} after {time.time() - start_time} seconds.")
                 if response:
-                    from_value = None
-                    to = To(x=response.to_value.x, y=response.to_value.y)
-                    if response.from_value:
-                        from_value = From(x=response.from_value.x, y=response.from_value.y)
-                    response = Data(class_value="move", from_value=from_value, to=to)
-                    self.send_message_to_room(room_id, response)
+                    self.send_message_to_room(room_id, _convertMoveToData(response))
             if isinstance(data, ObservableRoomMessage):
                 if isinstance(data, State):
-                    game_state = GameState(turn=data.turn, start_team=Team(data.start_team),
-                                           board=_convertBoard(data.board), last_move=data.last_move,
-                                           fishes=penguins.Fishes(data.fishes.int_value[0], data.fishes.int_value[1]))
+                    game_state = _convertState(data)
+                    if self.strict and self.game_state:
+                        self._check_state(self.game_state, game_state)
+                    self.game_state = game_state
                     self._game_handler.history.append(game_state)
                     self._game_handler.on_update(game_state)
                 elif isinstance(data, Result):
+                    self._remove_resume_token()
                     self._game_handler.history.append(data)
                     self._game_handler.on_game_over(data)
-            if isinstance(data, Error):
+            if isinstance(data, WelcomeMessage):
+                self.team = data.team
+                if self.requested_color and self.requested_color != data.team:
+                    logging.warning(f"Requested to play as team {self.requested_color}, "
+                                    f"but the server assigned team {data.team}. Playing as team {data.team}.")
+                self._game_handler.on_welcome_message(data)
+            if isinstance(data, Error) and self._rejoining:
+                self._rejoin_failed(data.message)
+            elif isinstance(data, Error):
                 logging.error(data.message)
                 self._game_handler.history.append(data)
                 self._game_handler.on_error(data.message)
             else:
                 self._game_handler.on_room_message(data)
         elif isinstance(message, Joined):
+            self._rejoining = False
+            self.room_id = message.room_id
+            logging.info(f"Joined room {message.room_id}.")
+            self._write_resume_token()
             self._game_handler.on_game_joined(room_id=message.room_id)
         elif isinstance(message, Left):
             self._game_handler.on_game_left()
 
+    def _is_blocked(self) -> bool:
+        """
+        Checks if the team of this client has no possible moves in the last received state,
+        so that calling the logic would leave it without any move to choose from.
+        The protocol has no message to pass a turn. The server skips a blocked team on its own and never requests
+        a move from it, so a move request for a blocked team means, that the client's state is out of sync.
+        """
+        if not self.game_state:
+            return False
+        return next(self.game_state.iter_moves(self.team or self.game_state.current_team), None) is None
+
+    def _check_state(self, previous: GameState, received: GameState):
+        """
+        Performs the last move of the received state on the previous state and compares the board and the fish
+        of both, to find mistakes in the simulation of the game early. Divergences are logged as warnings.
+
+        :param previous: The last state received before.
+        :param received: The state just received from the server.
+        """
+        if received.turn != previous.turn + 1 or not received.last_move:
+            return
+        if not previous.is_valid_move(received.last_move):
+            logging.warning(f"The last move {received.last_move} of turn {received.turn} from the server "
+                            f"is invalid in the simulated state.")
+            return
+        simulated = previous.perform_move(received.last_move)
+        if simulated.fishes != received.fishes:
+            logging.warning(f"The fish of turn {received.turn} from the server diverge from the simulated ones: "
+                            f"{received.fishes.fishes_one}:{received.fishes.fishes_two} instead of "
+                            f"{simulated.fishes.fishes_one}:{simulated.fishes.fishes_two}.")
+        differences = simulated.board.compare_to(received.board)
+        if differences:
+            logging.warning(f"The board of turn {received.turn} from the server diverges from the simulated one on "
+                            f"{', '.join(str(field.coordinate) for field in differences)}.")
+
+    def _rejoin_failed(self, reason: str):
+        """
+        Removes the resume token of a room, that can't be rejoined, and joins any open game instead.
+
+        :param reason: Why the room can't be rejoined.
+        """
+        logging.warning(f"Can't rejoin the room of the resume token, because {reason}. Joining a new game instead.")
+        self._rejoining = False
+        self._remove_resume_token()
+        self.join_game()
+
+    def _write_resume_token(self):
+        """
+        Stores the joined room, so that the client can rejoin the game after a crash.
+        """
+        if not self.resume_token_path:
+            return
+        with open(self.resume_token_path, "w") as file:
+            json.dump({"room_id": self.room_id}, file)
+        logging.debug(f"Stored the resume token to {self.resume_token_path}.")
+
+    def _remove_resume_token(self):
+        """
+        Removes the resume token, since the game is over and can't be rejoined.
+        """
+        if self.resume_token_path and os.path.exists(self.resume_token_path):
+            os.remove(self.resume_token_path)
+            logging.debug(f"Removed the resume token {self.resume_token_path}.")
+
     def start(self):
         """
         Starts the client loop.
@@ -182,7 +349,9 @@ class _PlayerClient(_XFluxClient):
             if self._network_interface.connected:
                 response = self._receive()
                 if isinstance(response, ProtocolPacket):
-                    if isinstance(response, Left):
+                    if isinstance(response, Left) and self._rejoining:
+                        self._rejoin_failed("the server left the room")
+                    elif isinstance(response, Left):
                         if not self.survive:
                             logging.info("The server left.")
                             self.stop()
@@ -1,9 +1,13 @@
 """
 This is the plugin for this year's game `Penguins`.
 """
+import json
 import logging
 import math
-from typing import List, Union, Optional
+import random
+import struct
+from enum import Enum
+from typing import Dict, Iterator, List, Union, Optional, Tuple
 from warnings import warn
 
 _hexagonTemplate = [
@@ -203,26 +207,30 @@ class CartesianCoordinate:
         """
         return HexCoordinate(x=self.x * 2 + (1 if self.y % 2 == 1 else 0), y=self.y)
 
-    def to_index(self) -> Optional[int]:
+    def to_index(self, width: int = 8, height: int = 8) -> Optional[int]:
         """
         Converts the cartesian coordinate to an index.
 
+        :param width: The amount of fields in one row of the board.
+        :param height: The amount of rows of the board.
         :return: The index or None if the coordinate is not valid.
         """
-        if 0 <= self.x <= 7 and 0 <= self.y <= 7:
-            return self.y * 8 + self.x
+        if 0 <= self.x < width and 0 <= self.y < height:
+            return self.y * width + self.x
         return None
 
     @staticmethod
-    def from_index(index: int) -> Optional['CartesianCoordinate']:
+    def from_index(index: int, width: int = 8, height: int = 8) -> Optional['CartesianCoordinate']:
         """
         Converts an index to a cartesian coordinate.
 
         :param index: The index to convert.
+        :param width: The amount of fields in one row of the board.
+        :param height: The amount of rows of the board.
         :return: The cartesian coordinate.
         """
-        if 0 <= index <= 63:
-            return CartesianCoordinate(x=index % 8, y=int(index / 8))
+        if 0 <= index < width * height:
+            return CartesianCoordinate(x=index % width, y=int(index / width))
         raise IndexError("Index out of range.")
 
     def __repr__(self) -> str:
@@ -256,6 +264,31 @@ class HexCoordinate:
         """
         return CartesianCoordinate(x=math.floor((self.x / 2 - (1 if self.y % 2 == 1 else 0)) + 0.5), y=self.y)
 
+    def to_index(self, width: int = 8) -> int:
+        """
+        Converts the hex coordinate to the index of a flat array of the board.
+        The fields are laid out row by row from the top, each row from left to right,
+        so the index is `y * width` plus the cartesian x-coordinate of the field.
+
+        :param width: The amount of fields in one row of the board.
+        :return: The index.
+        """
+        return self.y * width + self.to_cartesian().x
+
+    @staticmethod
+    def from_index(index: int, width: int = 8) -> 'HexCoordinate':
+        """
+        Converts the index of a flat array of the board to a hex coordinate. This is the inverse of `to_index`.
+
+        :param index: The index to convert.
+        :param width: The amount of fields in one row of the board.
+        :return: The hex coordinate.
+        :raise IndexError: If the index is negative.
+        """
+        if index < 0:
+            raise IndexError(f"Index out of range: {index}")
+        return CartesianCoordinate(x=index % width, y=index // width).to_hex()
+
     def to_vector(self) -> Vector:
         """
         Converts the hex coordinate to a vector.
@@ -301,12 +334,55 @@ class HexCoordinate:
         """
         return self.to_vector().subtraction(other.to_vector()).magnitude()
 
+    def hex_distance(self, other: 'HexCoordinate') -> int:
+        """
+        Calculates how many steps to neighboring fields lie between two hex coordinates, unlike `distance`,
+        which measures the straight line between them.
+
+        :param other: The other hex coordinate to calculate the distance to.
+        :return: The amount of steps between the two hex coordinates.
+        """
+        d_x = abs(self.x - other.x)
+        d_y = abs(self.y - other.y)
+        return d_y + max(0, (d_x - d_y) // 2)
+
+    def direction_to(self, other: 'HexCoordinate') -> Optional[Vector]:
+        """
+        Calculates the direction from this hex coordinate towards the other one.
+
+        :param other: The hex coordinate to point to.
+        :return: One of the six `Vector.directions`, or None if both coordinates don't lie in one line
+                 or are the same.
+        """
+        d_x = other.x - self.x
+        d_y = other.y - self.y
+        if d_y == 0 and d_x != 0 and d_x % 2 == 0:
+            return Vector(2 if d_x > 0 else -2, 0)
+        if d_y != 0 and abs(d_x) == abs(d_y):
+            return Vector(1 if d_x > 0 else -1, 1 if d_y > 0 else -1)
+        return None
+
+    def is_on_board(self, width: int = 8, height: int = 8) -> bool:
+        """
+        Checks if the hex coordinate lies on a board with the given dimensions.
+        Since odd rows are shifted by half a field, x and y must be both even or both odd.
+
+        :param width: The amount of fields in one row of the board.
+        :param height: The amount of rows of the board.
+        :return: True if the coordinate is on the board, False otherwise.
+        """
+        cartesian = self.to_cartesian()
+        return self.x % 2 == self.y % 2 and 0 <= cartesian.x < width and 0 <= cartesian.y < height
+
     def __repr__(self) -> str:
         return f"HexCoordinate({self.x}, {self.y})"
 
     def __eq__(self, other: object) -> bool:
         return isinstance(other, HexCoordinate) and self.x == other.x and self.y == other.y
 
+    def __hash__(self) -> int:
+        return hash((self.x, self.y))
+
 
 class Move:
     """
@@ -321,6 +397,18 @@ class Move:
         self.from_value = from_value
         self.to_value = to_value
 
+    def from_coordinate(self) -> Optional[HexCoordinate]:
+        """
+        :return: The origin of the move, or None if the move is a placement.
+        """
+        return self.from_value
+
+    def to_coordinate(self) -> HexCoordinate:
+        """
+        :return: The destination of the move.
+        """
+        return self.to_value
+
     def get_delta(self):
         """
         Gets the distance between the origin and the destination.
@@ -337,12 +425,105 @@ class Move:
         """
         return Move(from_value=self.to_value, to_value=self.from_value)
 
+    def to_dict(self) -> dict:
+        """
+        :return: The move as a dictionary, that can be serialized to json. The origin is None for placements.
+        """
+        from_value = {"x": self.from_value.x, "y": self.from_value.y} if self.from_value else None
+        return {"from": from_value, "to": {"x": self.to_value.x, "y": self.to_value.y}}
+
+    def to_xml(self, room_id: str = None) -> str:
+        """
+        Creates the xml of the move in the format the server expects.
+        Placements only contain the destination, while sliding moves contain the origin and the destination.
+
+        :param room_id: If set, the move is wrapped in the room message of this room, as it is sent to the server.
+        :return: The xml of the move.
+        """
+        data = '<data class="move">'
+        if self.from_value:
+            data += f'<from x="{self.from_value.x}" y="{self.from_value.y}"/>'
+        data += f'<to x="{self.to_value.x}" y="{self.to_value.y}"/></data>'
+        if room_id is None:
+            return data
+        return f'<room roomId="{room_id}">{data}</room>'
+
+    def to_notation(self) -> str:
+        """
+        Converts the move to a short text.
+        Placements are written as `@x,y` and sliding moves as `x,y->x,y`, both in hex coordinates.
+
+        :return: The notation of the move, that `from_notation` reads.
+        """
+        to_value = f"{self.to_value.x},{self.to_value.y}"
+        if not self.from_value:
+            return "@" + to_value
+        return f"{self.from_value.x},{self.from_value.y}->{to_value}"
+
+    @staticmethod
+    def from_notation(notation: str) -> 'Move':
+        """
+        Reads a move from the notation of `to_notation`.
+
+        :param notation: The notation of the move, e.g. `@3,1` or `3,1->7,1`.
+        :return: The move.
+        :raise ValueError: If the notation is not valid.
+        """
+
+        def coordinate(text: str) -> HexCoordinate:
+            x, y = text.split(",")
+            return HexCoordinate(int(x), int(y))
+
+        try:
+            if notation.startswith("@"):
+                return Move(to_value=coordinate(notation[1:]))
+            from_value, to_value = notation.split("->")
+            return Move(from_value=coordinate(from_value), to_value=coordinate(to_value))
+        except ValueError:
+            raise ValueError(f"Invalid notation of a move: {notation}") from None
+
+    def sort_key(self) -> Tuple[int, int, int, int]:
+        """
+        The key moves are ordered by: the origin first, then the destination, both by x and then by y.
+        Placements have no origin and come before all sliding moves.
+        A move doesn't know its team, so moves of both teams are only ordered by their coordinates.
+
+        :return: The x- and y-coordinates of the origin, -1 for placements, and of the destination.
+        """
+        from_value = self.from_value or HexCoordinate(-1, -1)
+        return from_value.x, from_value.y, self.to_value.x, self.to_value.y
+
     def __str__(self) -> str:
         return "Move(from = {}, to = {})".format(self.from_value, self.to_value)
 
     def __eq__(self, __o: object) -> bool:
-        return isinstance(__o, Move) and self.to_value == __o.to_value and \
-               (self.from_value is None or self.from_value == __o.from_value)
+        return isinstance(__o, Move) and self.to_value == __o.to_value and self.from_value == __o.from_value
+
+    def __hash__(self) -> int:
+        return hash((self.from_value, self.to_value))
+
+    def __lt__(self, __o: object) -> bool:
+        """
+        Orders moves by their `sort_key`, so that sorting a list of moves gives the same order every time.
+        """
+        if not isinstance(__o, Move):
+            return NotImplemented
+        return self.sort_key() < __o.sort_key()
+
+    def __le__(self, __o: object) -> bool:
+        if not isinstance(__o, Move):
+            return NotImplemented
+        return self.sort_key() <= __o.sort_key()
+
+    def __gt__(self, __o: object) -> bool:
+        if not isinstance(__o, Move):
+            return NotImplemented
+        return self.sort_key() > __o.sort_key()
+
+    def __ge__(self, __o: object) -> bool:
+        if not isinstance(__o, Move):
+            return NotImplemented
+        return self.sort_key() >= __o.sort_key()
 
 
 class Team:
@@ -376,6 +557,24 @@ class Team:
         else:
             raise Exception(f"Invalid : {color}")
 
+    @classmethod
+    def all(cls) -> List['Team']:
+        """
+        :return: Both teams, ONE first.
+        """
+        return [cls("ONE"), cls("TWO")]
+
+    @classmethod
+    def from_index(cls, index: int) -> 'Team':
+        """
+        :param index: The index of the team in `all`, 0 for ONE and 1 for TWO.
+        :return: The team with the index.
+        :raise IndexError: If the index is neither 0 nor 1.
+        """
+        if index not in (0, 1):
+            raise IndexError(f"There is no team with the index {index}.")
+        return cls.all()[index]
+
     def team(self) -> 'Team':
         """
         :return: The team object.
@@ -397,9 +596,15 @@ class Team:
     def __eq__(self, __o: object) -> bool:
         return isinstance(__o, Team) and self.team_enum['name'] == __o.team_enum['name']
 
+    def __hash__(self) -> int:
+        return hash(self.team_enum['name'])
+
     def __str__(self) -> str:
         return self.team_enum['name']
 
+    def __repr__(self) -> str:
+        return self.team_enum['name']
+
 
 class Field:
     """
@@ -416,7 +621,7 @@ class Field:
         """
         self.coordinate = coordinate
         self.field: Union[int, str, Team]
-        if isinstance(field, int):
+        if isinstance(field, (int, Team)):
             self.field = field
         elif field.isalpha():
             self.field = Team(field)
@@ -457,6 +662,49 @@ class Field:
             return f"Field({self.coordinate}, {self.field})"
 
 
+class UndoToken:
+    """
+    Remembers the fields a move has changed on a board, so that `Board.unmake_move` can restore them.
+    """
+
+    def __init__(self, move: Move, to_field: Union[int, Team], from_field: Union[int, Team, None]):
+        """
+        :param move: The move that has been made.
+        :param to_field: The state of the destination field before the move.
+        :param from_field: The state of the origin field before the move, None for placements.
+        """
+        self.move = move
+        self.to_field = to_field
+        self.from_field = from_field
+
+
+class BoardStats:
+    """
+    Bundles the counts of penguins and fish on a board.
+    """
+
+    def __init__(self, penguins_one: int, penguins_two: int, total_fish: int, fish_fields: Dict[int, int]):
+        """
+        :param penguins_one: The amount of penguins of team ONE.
+        :param penguins_two: The amount of penguins of team TWO.
+        :param total_fish: The amount of fish on all unoccupied fields.
+        :param fish_fields: The amount of unoccupied fields by their amount of fish, from 1 to 4.
+        """
+        self.penguins_one = penguins_one
+        self.penguins_two = penguins_two
+        self.total_fish = total_fish
+        self.fish_fields = fish_fields
+
+    def __eq__(self, __o: object) -> bool:
+        return isinstance(__o, BoardStats) and self.penguins_one == __o.penguins_one and \
+            self.penguins_two == __o.penguins_two and self.total_fish == __o.total_fish and \
+            self.fish_fields == __o.fish_fields
+
+    def __repr__(self) -> str:
+        return f"BoardStats(penguins_one={self.penguins_one}, penguins_two={self.penguins_two}, " \
+               f"total_fish={self.total_fish}, fish_fields={self.fish_fields})"
+
+
 class Board:
     """
     Class which represents a game board. Consisting of a two-dimensional array of fields.
@@ -481,6 +729,26 @@ class Board:
                     fields.append(field)
         return fields
 
+    def unoccupied_fields(self) -> List[HexCoordinate]:
+        """
+        Unlike `get_empty_fields`, which only lists the fields without fish, these are all fields without a penguin.
+
+        :return: A list of the coordinates of all fields without a penguin, row by row from the top.
+        """
+        return [field.coordinate for row in self._game_field for field in row if not field.is_occupied()]
+
+    def occupied_mask(self) -> int:
+        """
+        Combines the penguins of both teams to a bitmask.
+
+        :return: A number, whose bit with the `coordinate_to_index` of a field is set, if a penguin is on it.
+        """
+        mask = 0
+        for index, field in enumerate(field for row in self._game_field for field in row):
+            if field.is_occupied():
+                mask |= 1 << index
+        return mask
+
     def is_occupied(self, coordinates: HexCoordinate) -> bool:
         """
         :param coordinates: The coordinates of the field.
@@ -495,20 +763,19 @@ class Board:
         :param coordinates: The coordinates of the field.
         :return: True if the field is valid, false otherwise.
         """
-        arrayCoordinates = coordinates.to_cartesian()
-        return 0 <= arrayCoordinates.x < self.width() and 0 <= arrayCoordinates.y < self.height()
+        return coordinates.is_on_board(self.width(), self.height())
 
     def width(self) -> int:
         """
-        :return: The width of the board.
+        :return: The width of the board, that is the amount of fields in one row.
         """
-        return len(self._game_field)
+        return len(self._game_field[0]) if self._game_field else 0
 
     def height(self) -> int:
         """
-        :return: The height of the board.
+        :return: The height of the board, that is the amount of rows.
         """
-        return len(self._game_field[0])
+        return len(self._game_field)
 
     def _get_field(self, x: int, y: int) -> Field:
         """
@@ -547,19 +814,66 @@ class Board:
             return self._get_field(cartesian.x, cartesian.y)
         return None
 
+    def __getitem__(self, position: HexCoordinate) -> Union[int, Team]:
+        """
+        Makes the board subscriptable with hex coordinates, e.g. `board[HexCoordinate(3, 1)]`.
+
+        :param position: The position of the field.
+        :return: The amount of fish on the field, or the team if it is occupied by a penguin.
+        :raise IndexError: If the position is not valid.
+        """
+        return self.get_field(position).field
+
+    def __iter__(self) -> Iterator[Tuple[HexCoordinate, Union[int, Team]]]:
+        """
+        Makes the board iterable, e.g. `for coordinate, content in board:`, row by row from the top,
+        each row from left to right.
+
+        :return: An iterator over the coordinate of every field with its amount of fish or its team.
+        """
+        for row in self._game_field:
+            for field in row:
+                yield field.coordinate, field.field
+
+    def index_to_coordinate(self, index: int) -> HexCoordinate:
+        """
+        Converts the index of a field, that counts the fields row by row from the top left corner,
+        to its hex coordinate on this board.
+
+        :param index: The index of the field.
+        :return: The coordinate of the field.
+        :raise IndexError: If the index is not on the board.
+        """
+        if not 0 <= index < self.width() * self.height():
+            raise IndexError(f"Index out of range: {index}")
+        return HexCoordinate.from_index(index, self.width())
+
+    def coordinate_to_index(self, coordinate: HexCoordinate) -> int:
+        """
+        Converts the hex coordinate of a field to its index on this board, the inverse of `index_to_coordinate`.
+
+        :param coordinate: The coordinate of the field.
+        :return: The index of the field.
+        :raise IndexError: If the coordinate is not on the board.
+        """
+        if not self.is_valid(coordinate):
+            raise IndexError(f"Index out of range: [x={coordinate.x}, y={coordinate.y}]")
+        return coordinate.to_index(self.width())
+
     def get_field_by_index(self, index: int) -> Field:
         """
         Gets the field at the given index. The index is the position of the field in the board.
         The field of the board is calculated as follows:
 
-        - `x = index / width`
-        - `y = index % width`
+        - `x = index % width`
+        - `y = index / width`
         - The index is 0-based. The index is calculated from the top left corner of the board.
 
         :param index: The index of the field.
         :return: The field at the given index.
+        :raise IndexError: If the index is not on the board.
         """
-        return self.get_field(CartesianCoordinate.from_index(index).to_hex())
+        return self.get_field(CartesianCoordinate.from_index(index, self.width(), self.height()).to_hex())
 
     def get_all_fields(self) -> List[Field]:
         """
@@ -607,6 +921,23 @@ class Board:
                 return False
         return True
 
+    def slides_in_direction(self, origin: HexCoordinate, direction: Vector) -> List[HexCoordinate]:
+        """
+        Gets the fields, that a penguin can slide to in one direction.
+        The penguin slides until it hits a field without fish, a penguin of any team or the edge of the board.
+
+        :param origin: The position of the penguin.
+        :param direction: One of the six `Vector.directions`.
+        :return: A list of the reachable coordinates, from the nearest to the farthest.
+        """
+        destinations = []
+        for i in range(1, max(self.width(), self.height())):
+            destination = origin.add_vector(direction.scalar_product(i))
+            if not self._is_destination_valid(destination):
+                break
+            destinations.append(destination)
+        return destinations
+
     def get_moves_in_direction(self, origin: HexCoordinate, direction: Vector) -> List[Move]:
         """
         Gets all moves in the given direction from the given origin.
@@ -615,14 +946,8 @@ class Board:
         :param direction: The direction of the move.
         :return: A list with all moves that fulfill the criteria.
         """
-        moves = []
-        for i in range(1, self.width()):
-            destination = origin.add_vector(direction.scalar_product(i))
-            if self._is_destination_valid(destination):
-                moves.append(Move(from_value=origin, to_value=destination))
-            else:
-                break
-        return moves
+        return [Move(from_value=origin, to_value=destination)
+                for destination in self.slides_in_direction(origin, direction)]
 
     def _is_destination_valid(self, field: HexCoordinate) -> bool:
         return self.is_valid(field) and not self.is_occupied(field) and not \
@@ -643,6 +968,85 @@ class Board:
             moves.extend(self.get_moves_in_direction(position, direction))
         return moves
 
+    def is_field_reachable(self, from_value: HexCoordinate, to_value: HexCoordinate, team: Team) -> bool:
+        """
+        Checks if a penguin of the given team can slide from one field to the other in one move.
+
+        :param from_value: The origin, on which the penguin of the team has to be.
+        :param to_value: The destination.
+        :param team: The team of the penguin.
+        :return: True if the destination is one of the possible destinations of the penguin, False otherwise.
+        """
+        field = self.get_field_or_none(from_value)
+        if not field or field.get_team() != team:
+            return False
+        return Move(from_value=from_value, to_value=to_value) in self.possible_moves_from(from_value)
+
+    def fish_distance_map(self, from_value: HexCoordinate) -> List[Tuple[HexCoordinate, int]]:
+        """
+        Calculates how many moves a penguin needs at least to reach each field.
+        The board is taken as it is, so the penguins of both teams block the way.
+
+        :param from_value: The position of the penguin.
+        :return: Every reachable field with the amount of moves needed to reach it, ordered by the amount of moves.
+        """
+        distances = []
+        visited = {from_value}
+        frontier = [from_value]
+        distance = 0
+        while frontier:
+            distance += 1
+            next_frontier = []
+            for position in frontier:
+                for direction in Vector().directions:
+                    for move in self.get_moves_in_direction(position, direction):
+                        if move.to_value not in visited:
+                            visited.add(move.to_value)
+                            distances.append((move.to_value, distance))
+                            next_frontier.append(move.to_value)
+            frontier = next_frontier
+        return distances
+
+    def region_size(self, from_value: HexCoordinate, team: Team) -> int:
+        """
+        Counts the fields, that the penguin can reach with any amount of slides, like `fish_distance_map`.
+        In the endgame this approximates how many more moves the penguin has, while filling its region.
+
+        :param from_value: The position of the penguin.
+        :param team: The team of the penguin.
+        :return: The amount of reachable fields.
+        :raise ValueError: If there is no penguin of the team on the position.
+        """
+        field = self.get_field_or_none(from_value)
+        if not field or field.get_team() != team:
+            raise ValueError(f"There is no penguin of team {team} on {from_value}.")
+        return len(self.fish_distance_map(from_value))
+
+    def can_reach(self, from_value: HexCoordinate, to_value: HexCoordinate) -> bool:
+        """
+        Checks if a penguin on the origin could reach the destination with any amount of its own moves.
+        The moves of the opponent are ignored, so the board is taken as it is, like in `fish_distance_map`.
+
+        :param from_value: The position of the penguin.
+        :param to_value: The destination.
+        :return: True if the destination is the origin or can be reached from it, False otherwise.
+        """
+        return from_value == to_value or any(position == to_value for position, _ in self.fish_distance_map(from_value))
+
+    def distance_to_nearest_fish(self, from_value: HexCoordinate, min_value: int = 1) -> Optional[int]:
+        """
+        Searches the unoccupied field with at least the given amount of fish, that is closest to the position.
+        The distance is measured in steps to neighboring fields regardless of the way,
+        unlike `fish_distance_map`, which counts the moves a penguin needs.
+
+        :param from_value: The position to measure the distance from.
+        :param min_value: The least amount of fish the field must have.
+        :return: The `HexCoordinate.hex_distance` to the closest of these fields, or None if there is none.
+        """
+        distances = [from_value.hex_distance(field.coordinate) for row in self._game_field for field in row
+                     if not field.is_occupied() and field.get_fish() >= min_value]
+        return min(distances) if distances else None
+
     def get_penguins(self) -> List[Field]:
         """
         Searches the board for all penguins.
@@ -667,6 +1071,32 @@ class Board:
                     teams_penguins.append(coordinates)
         return teams_penguins
 
+    def stats(self) -> BoardStats:
+        """
+        Counts the penguins of both teams and the fish on the board at once.
+
+        :return: The counts of the board.
+        """
+        fish_fields = {fish: 0 for fish in range(1, 5)}
+        penguins = {Team("ONE"): 0, Team("TWO"): 0}
+        for row in self._game_field:
+            for field in row:
+                if field.is_occupied():
+                    penguins[field.get_team()] += 1
+                elif field.get_fish():
+                    fish_fields[field.get_fish()] += 1
+        total_fish = sum(fish * count for fish, count in fish_fields.items())
+        return BoardStats(penguins[Team("ONE")], penguins[Team("TWO")], total_fish, fish_fields)
+
+    def penguin_count(self, team: Team) -> int:
+        """
+        Counts the penguins of the given team on the board.
+
+        :param team: The team to count the penguins of.
+        :return: The amount of penguins, between 0 and 4.
+        """
+        return len(self.get_teams_penguins(team))
+
     def get_most_fish(self) -> List[Field]:
         """
         Returns a list of all fields with the most fish.
@@ -675,11 +1105,104 @@ class Board:
         """
 
         fields = list(filter(lambda field_x: not field_x.is_occupied(), self.get_all_fields()))
-        fields.sort(key=lambda field_x: field_x.get_fish(), reverse=True)
-        for i, field in enumerate(fields):
-            if field.get_fish() < fields[0].get_fish():
-                fields = fields[:i]
-        return fields
+        fields.sort(key=lambda field_x: self.get_fish_or_zero(field_x.coordinate), reverse=True)
+        most_fish = self.get_fish_or_zero(fields[0].coordinate) if fields else 0
+        return [field for field in fields if self.get_fish_or_zero(field.coordinate) == most_fish]
+
+    def get_fish_or_zero(self, position: HexCoordinate) -> int:
+        """
+        Looks up the amount of fish on the field at the given position.
+        Unlike `Field.get_fish`, occupied fields count as zero fish, so the result can always be sorted and summed.
+
+        :param position: The position of the field.
+        :return: The amount of fish on the field, 0 if the field is occupied.
+        :raise IndexError: If the position is not valid.
+        """
+        return self.get_field(position).get_fish() or 0
+
+    def fields_with_fish(self, value: int) -> List[HexCoordinate]:
+        """
+        Searches the board for all unoccupied fields with the given amount of fish.
+
+        :param value: The amount of fish, between 1 and 4.
+        :return: A list of the coordinates of all fields, that hold exactly that many fish.
+        :raise ValueError: If the amount of fish is not between 1 and 4.
+        """
+        if not 1 <= value <= 4:
+            raise ValueError(f"A field can only hold between 1 and 4 fish: {value}")
+        return [field.coordinate for row in self._game_field for field in row if field.get_fish() == value]
+
+    def single_fish_fields(self) -> List[HexCoordinate]:
+        """
+        Searches the board for all fields, on which a penguin may be placed during the placement phase.
+        These are the unoccupied fields with exactly one fish.
+        They are listed row by row from the top, each row from left to right,
+        since the fields are stored as rows of cartesian coordinates.
+
+        :return: A list of the coordinates of all unoccupied fields with one fish.
+        """
+        return self.fields_with_fish(1)
+
+    def is_edge_field(self, coordinate: HexCoordinate) -> bool:
+        """
+        Checks if the field lies at the edge of the board, that is, if it has fewer than six neighbors on the board.
+
+        :param coordinate: The coordinate of the field.
+        :return: True if the field is an edge field, False otherwise.
+        :raise IndexError: If the coordinate is not on the board.
+        """
+        if not self.is_valid(coordinate):
+            raise IndexError(f"Index out of range: [x={coordinate.x}, y={coordinate.y}]")
+        return not all(self.is_valid(neighbor) for neighbor in coordinate.get_neighbors())
+
+    def center_fields(self) -> List[HexCoordinate]:
+        """
+        Searches for the fields in the center of the board, that are the fields farthest away from the edge fields,
+        counted in steps to neighboring fields.
+
+        :return: A list of the coordinates of the center fields, row by row from the top.
+        """
+        coordinates = [field.coordinate for row in self._game_field for field in row]
+        frontier = [coordinate for coordinate in coordinates if self.is_edge_field(coordinate)]
+        visited = set(frontier)
+        while True:
+            next_frontier = []
+            for coordinate in frontier:
+                for neighbor in coordinate.get_neighbors():
+                    if neighbor not in visited and self.is_valid(neighbor):
+                        visited.add(neighbor)
+                        next_frontier.append(neighbor)
+            if not next_frontier:
+                return sorted(frontier, key=lambda coordinate: (coordinate.y, coordinate.x))
+            frontier = next_frontier
+
+    def connected_components(self) -> List[List[HexCoordinate]]:
+        """
+        Partitions all fields, that a penguin could move onto, into groups of fields reachable from each other.
+        Penguins slide over fields with fish only, so the groups are split by penguins and water fields,
+        no matter which team is to move. Penguins and water fields are part of no group.
+        Groups and their fields are listed row by row from the top, each row from left to right.
+
+        :return: A list of groups, each a list of the coordinates of its fields.
+        """
+        components = []
+        visited = set()
+        for row in self._game_field:
+            for field in row:
+                if field.coordinate in visited or not field.get_fish():
+                    continue
+                visited.add(field.coordinate)
+                component = []
+                stack = [field.coordinate]
+                while stack:
+                    position = stack.pop()
+                    component.append(position)
+                    for neighbor in position.get_neighbors():
+                        if neighbor not in visited and self._is_destination_valid(neighbor):
+                            visited.add(neighbor)
+                            stack.append(neighbor)
+                components.append(sorted(component, key=lambda coordinate: (coordinate.y, coordinate.x)))
+        return components
 
     def get_board_intersection(self, other: 'Board') -> List[Field]:
         """
@@ -699,22 +1222,122 @@ class Board:
         """
         return [field for field in self.get_all_fields() if field in other]
 
-    def _move(self, move: Move) -> 'Board':
+    def simulate_move(self, move: Move, team: Team = None) -> 'Board':
         """
-        Moves the penguin from the origin to the destination.
+        Simulates the move on a copy of this board, without any side effects on this board.
+        The penguin is placed on the destination and, if it is a sliding move, the origin is left as water.
 
-        :param move: The move to execute.
+        :param move: The move to simulate.
+        :param team: The team of the penguin. Only needed for placements,
+                     since sliding moves take the team of the penguin on the origin.
         :return: The new board with the moved penguin.
+        :raise ValueError: If the team of a placement is unknown.
         """
-        new_board = Board(self._game_field)
-        to_field = new_board.get_field(move.to_value)
-        to_field_coo = move.to_value.to_cartesian()
-        new_board._game_field[to_field_coo.x][to_field_coo.y] = Field(coordinate=move.to_value, field=to_field.field)
         if move.from_value:
-            from_field_coo = move.from_value.to_cartesian()
-            new_board._game_field[from_field_coo.x][from_field_coo.y] = Field(coordinate=move.from_value, field=0)
+            team = team or self.get_field(move.from_value).get_team()
+        if not team:
+            raise ValueError(f"The team of the move is unknown: {move}")
+        new_board = self._copy()
+        new_board._set_field(move.to_value, team)
+        if move.from_value:
+            new_board._set_field(move.from_value, 0)
         return new_board
 
+    def apply_move(self, move: Move, team: Team = None) -> 'Board':
+        """
+        Applies the move to a new board and leaves this board unchanged. This is an alias of `simulate_move`.
+
+        The destination field is replaced by the penguin of the team, so the fish on it are gone.
+        For sliding moves the origin field is replaced by water, that is a field with no fish.
+        All other fields are copied.
+
+        :param move: The move to apply.
+        :param team: The team of the penguin. Only needed for placements.
+        :return: The new board with the moved penguin.
+        :raise ValueError: If the team of a placement is unknown.
+        """
+        return self.simulate_move(move, team)
+
+    def place_penguin(self, position: HexCoordinate, team: Team):
+        """
+        Places a penguin of the team on the field in place.
+        The fish on the field are gone, like when a penguin moves onto a field.
+
+        :param position: The position of the field.
+        :param team: The team of the penguin.
+        :raise IndexError: If the position is not valid.
+        :raise ValueError: If there is already a penguin on the field.
+        """
+        if self.get_field(position).is_occupied():
+            raise ValueError(f"There is already a penguin on {position}.")
+        self._set_field(position, team)
+
+    def remove_penguin(self, position: HexCoordinate, fish: int = 0):
+        """
+        Removes the penguin from the field in place.
+
+        :param position: The position of the field.
+        :param fish: The amount of fish, that are on the field after the penguin has been removed.
+                     Default is 0, so the field is water, like when a penguin leaves a field.
+        :raise IndexError: If the position is not valid.
+        :raise ValueError: If there is no penguin on the field.
+        """
+        if not self.get_field(position).is_occupied():
+            raise ValueError(f"There is no penguin on {position}.")
+        self._set_field(position, fish)
+
+    def make_move_in_place(self, move: Move, team: Team = None) -> UndoToken:
+        """
+        Makes the move on this board, without copying it like `simulate_move` does.
+        The move is not checked, so it has to be one of the possible moves.
+
+        :param move: The move to make.
+        :param team: The team of the penguin. Only needed for placements,
+                     since sliding moves take the team of the penguin on the origin.
+        :return: The token, that restores the board with `unmake_move`.
+        :raise ValueError: If the team of a placement is unknown.
+        """
+        from_field = self.get_field(move.from_value).field if move.from_value else None
+        if move.from_value:
+            team = team or self.get_field(move.from_value).get_team()
+        if not team:
+            raise ValueError(f"The team of the move is unknown: {move}")
+        token = UndoToken(move, self.get_field(move.to_value).field, from_field)
+        self._set_field(move.to_value, team)
+        if move.from_value:
+            self._set_field(move.from_value, 0)
+        return token
+
+    def unmake_move(self, token: UndoToken):
+        """
+        Restores the fields, that have been changed by `make_move_in_place`.
+        If several moves have been made, they have to be unmade in reverse order.
+
+        :param token: The token, that `make_move_in_place` has returned.
+        """
+        self._set_field(token.move.to_value, token.to_field)
+        if token.move.from_value:
+            self._set_field(token.move.from_value, token.from_field)
+
+    def _copy(self) -> 'Board':
+        """
+        Copies the board, so the fields of the copy can be changed without affecting this board.
+        *Used only internally*
+        """
+        return Board([[Field(coordinate=field.coordinate, field=field.field) for field in row]
+                      for row in self._game_field])
+
+    def _set_field(self, position: HexCoordinate, field: Union[int, Team]):
+        """
+        Replaces the field at the given position in place.
+        *Used only internally*
+
+        :param position: The position of the field.
+        :param field: The new state of the field. Can be either the number of fishes, or a Team.
+        """
+        cartesian = position.to_cartesian()
+        self._game_field[cartesian.y][cartesian.x] = Field(coordinate=position, field=field)
+
     @staticmethod
     def _fillUpString(placeholder: str, string: str) -> str:
         len_placeholder = len(placeholder)
@@ -742,33 +1365,201 @@ class Board:
                 result += "\n"
         print(result)
 
-    def __eq__(self, __o: 'Board'):
-        return self._game_field == __o._game_field
+    def to_strings(self) -> List[str]:
+        """
+        Converts the board to a compact form.
+        Every row of the board is a string with one character per field:
+        the amount of fish, or the letter of the team, whose penguin is on the field, R for ONE and B for TWO.
 
+        :return: The rows of the board as strings.
+        """
+        return ["".join(field.get_team().team_enum['letter'] if field.is_occupied() else str(field.field)
+                        for field in row) for row in self._game_field]
 
-class Fishes:
-    """
-    Represents the amount of fish each player has.
-    """
+    def rotated_coordinate(self, coordinate: HexCoordinate) -> HexCoordinate:
+        """
+        :param coordinate: The coordinate of a field.
+        :return: The coordinate of the field after rotating the board by 180 degrees.
+        """
+        cartesian = coordinate.to_cartesian()
+        return CartesianCoordinate(self.width() - 1 - cartesian.x, self.height() - 1 - cartesian.y).to_hex()
 
-    def __init__(self, fishes_one: int, fishes_two: int):
-        self.fishes_one = fishes_one
-        self.fishes_two = fishes_two
+    def rotated(self) -> 'Board':
+        """
+        Rotates the board by 180 degrees, so that the last field of the last row becomes the first one.
+        On boards with an even amount of rows, like the 8x8 board of the game, the rotation keeps all neighbors,
+        so the rotated board allows the same moves, only rotated.
 
-    def get_fish_by_team(self, team: Team):
+        :return: The rotated board.
         """
-        Looks up the amount of fish a team has.
+        return Board([[Field(CartesianCoordinate(x, y).to_hex(), field.field) for x, field in enumerate(reversed(row))]
+                      for y, row in enumerate(reversed(self._game_field))])
 
-        :param team: A team object, that represents the team to get the fish amount of.
-        :return: The amount of fish of the given team.
+    def mirrored_coordinate(self, coordinate: HexCoordinate) -> HexCoordinate:
         """
-        return self.fishes_one if team.team_enum == Team("ONE").team_enum else self.fishes_two
+        :param coordinate: The coordinate of a field.
+        :return: The coordinate of the field after mirroring the board at its middle row.
+        """
+        return HexCoordinate(coordinate.x, self.height() - 1 - coordinate.y)
 
+    def mirrored(self) -> 'Board':
+        """
+        Mirrors the board at its middle row, so that the last row becomes the first one.
+        On boards with an odd amount of rows, the mirroring keeps all neighbors,
+        so the mirrored board allows the same moves, only mirrored.
 
-class GameState:
-    """
-       A `GameState` contains all information, that describes the game state at a given time, that is, between two game
-       moves.
+        :return: The mirrored board.
+        """
+        return Board([[Field(CartesianCoordinate(x, y).to_hex(), field.field) for x, field in enumerate(row)]
+                      for y, row in enumerate(reversed(self._game_field))])
+
+    def _symmetric_coordinate(self, coordinate: HexCoordinate) -> HexCoordinate:
+        """
+        :return: The coordinate of the field on the board, that `canonical` compares this board to.
+        """
+        if self.height() % 2 == 0:
+            return self.rotated_coordinate(coordinate)
+        return self.mirrored_coordinate(coordinate)
+
+    def canonical(self) -> 'Board':
+        """
+        Picks the same one of all boards, that are symmetric to this board.
+        Since every other row is shifted by half a field, only few mappings keep the neighborhoods of the fields.
+        On boards with an even amount of rows, the symmetry group consists of the identity and the rotation
+        by 180 degrees, on boards with an odd amount of rows of the identity and the mirroring at the middle row.
+        The board, whose `to_strings` are lexicographically smaller, is picked.
+
+        :return: A copy of this board or of its symmetric board, which is the same for both of them.
+        """
+        symmetric = self.rotated() if self.height() % 2 == 0 else self.mirrored()
+        if symmetric.to_strings() < self.to_strings():
+            return symmetric
+        return self._copy()
+
+    def structural_hash(self) -> int:
+        """
+        Calculates a hash of the penguin and fish layout.
+        Boards with the same layout have the same hash, no matter how they were reached.
+
+        :return: The hash of the board.
+        """
+        return hash(tuple(tuple(str(field.field) for field in row) for row in self._game_field))
+
+    def __eq__(self, __o: 'Board'):
+        return isinstance(__o, Board) and self._game_field == __o._game_field
+
+    def __hash__(self) -> int:
+        return self.structural_hash()
+
+
+class Fishes:
+    """
+    Represents the amount of fish each player has.
+    """
+
+    def __init__(self, fishes_one: int, fishes_two: int):
+        self.fishes_one = fishes_one
+        self.fishes_two = fishes_two
+
+    def get_fish_by_team(self, team: Team):
+        """
+        Looks up the amount of fish a team has.
+        This is the accessor used by the tutorials, it returns the same as `fishes_one` or `fishes_two`.
+
+        :param team: A team object, that represents the team to get the fish amount of.
+        :return: The amount of fish of the given team.
+        """
+        return self.fishes_one if team.team_enum == Team("ONE").team_enum else self.fishes_two
+
+    def to_dict(self) -> dict:
+        """
+        :return: The fish of both teams as a dictionary, that can be serialized to json.
+        """
+        return {"ONE": self.fishes_one, "TWO": self.fishes_two}
+
+    def __repr__(self) -> str:
+        return f"Fishes(ONE={self.fishes_one}, TWO={self.fishes_two})"
+
+    def __eq__(self, __o: object) -> bool:
+        return isinstance(__o, Fishes) and self.fishes_one == __o.fishes_one and self.fishes_two == __o.fishes_two
+
+
+class MoveIterator:
+    """
+    Generates the possible moves of a team one after another, only when the next move is requested.
+    This saves generating all moves, if only the first few are needed.
+    The possible moves of the `GameState` are generated by this too, so they are in the same order.
+    """
+
+    def __init__(self, board: Board, team: Team):
+        """
+        :param board: The board to generate the moves on.
+        :param team: The team to generate the moves for.
+        """
+        self.board = board
+        self.team = team
+        self.generated = 0
+        self._moves = self._generate()
+
+    def _generate(self):
+        if self.board.penguin_count(self.team) < 4:
+            for position in self.board.single_fish_fields():
+                yield Move(from_value=None, to_value=position)
+        else:
+            for piece in self.board.get_teams_penguins(self.team):
+                for direction in Vector().directions:
+                    destination = piece.add_vector(direction)
+                    while self.board._is_destination_valid(destination):
+                        yield Move(from_value=piece, to_value=destination)
+                        destination = destination.add_vector(direction)
+
+    def __iter__(self) -> 'MoveIterator':
+        return self
+
+    def __next__(self) -> Move:
+        move = next(self._moves)
+        self.generated += 1
+        return move
+
+
+class GameOverError(Exception):
+    """
+    Raised when a move is performed on a game state, in which the game is already over.
+    """
+
+
+class GamePhase(Enum):
+    """
+    The phases of a game, one after the other.
+    """
+    PLACEMENT = "PLACEMENT"
+    """Both teams place their penguins on fields with one fish."""
+    MOVEMENT = "MOVEMENT"
+    """All penguins are placed and slide across the board."""
+    FINISHED = "FINISHED"
+    """No team can move anymore."""
+
+
+# the random keys of the zobrist hash, seven for each field index (zero to four fish, a penguin of team one or two),
+# which are drawn in the order of the indices from a seeded generator, so that they don't depend on the board sizes
+_zobrist_random = random.Random(0x50C4A)
+_zobrist_side_key = _zobrist_random.getrandbits(64)
+_zobrist_keys: List[List[int]] = []
+
+
+def _zobrist_key(index: int, field: Union[int, Team]) -> int:
+    """
+    Gets the zobrist key of the given field state on the field with the given index.
+    """
+    while len(_zobrist_keys) <= index:
+        _zobrist_keys.append([_zobrist_random.getrandbits(64) for _ in range(7)])
+    return _zobrist_keys[index][field if isinstance(field, int) else 5 + (field == Team("TWO"))]
+
+
+class GameState:
+    """
+       A `GameState` contains all information, that describes the game state at a given time, that is, between two game
+       moves.
 
        This includes:
             - the board
@@ -788,6 +1579,14 @@ class GameState:
        describing the then current state.
        """
 
+    # the weights of the baseline evaluation, that can be overwritten by subclasses
+    fish_weight: float = 1.0
+    mobility_weight: float = 0.1
+
+    # the header of to_bytes: width, height, turn, start team, fish of both teams and the last move,
+    # whose coordinates are -1 if they are missing
+    _bytes_header = struct.Struct(">BBHBHHbbbb")
+
     def __init__(self, board: Board, turn: int, start_team: Team, fishes: Fishes, last_move: Move = None):
         """
         Creates a new `GameState` with the given parameters.
@@ -808,6 +1607,131 @@ class GameState:
         self.fishes = fishes
         self.current_pieces = self.board.get_teams_penguins(self.current_team)
         self.possible_moves = self._get_possible_moves(self.current_team)
+        # the collected fish and the previous last move for reverting perform_move, which form a linked list,
+        # where a passed turn of perform_null_move has no fish
+        self._undo: Optional[tuple] = None
+        self._undo_length = 0
+        # the zobrist hash, which is calculated on demand and then updated by perform_move
+        self._zobrist: Optional[int] = None
+        # the length of the undo list, when with_history has been called
+        self._history_start: Optional[int] = None
+
+    def validate(self):
+        """
+        Checks that the game state could have been reached in a game.
+        Checked are:
+
+            - all rows of the board have the same length
+            - every field holds either 0 to 4 fish or a penguin of a team
+            - no field with a penguin holds any fish
+            - the coordinate of every field matches its position on the board, so no two fields share a position
+            - every team has at most four penguins
+            - the turn is not negative, the round matches the turn and every placed penguin took a turn
+            - every team has collected at least one fish for each placed penguin
+            - both teams together have collected no more than four fish per turn
+            - the collected fish and the fish on the board are no more than four per field
+
+        The initial board is not known, so neither the exact amount of fish nor whether a penguin
+        has been placed on or moved to water can be checked.
+
+        :raise ValueError: If the game state is not valid, with the broken rule in the message.
+        """
+        for y, row in enumerate(self.board._game_field):
+            if len(row) != len(self.board._game_field[0]):
+                raise ValueError(f"Row {y} has {len(row)} fields, but row 0 has {len(self.board._game_field[0])}.")
+            for x, field in enumerate(row):
+                position = CartesianCoordinate(x, y).to_hex()
+                if not isinstance(field.field, Team) and field.field not in range(5):
+                    raise ValueError(f"The field on {position} holds {field.field}, "
+                                     f"but only 0 to 4 fish or a penguin are allowed.")
+                if field.is_occupied() and field.get_fish():
+                    raise ValueError(f"The field on {position} holds a penguin and {field.get_fish()} fish.")
+                if field.coordinate != position:
+                    raise ValueError(f"The field on {position} has the coordinate {field.coordinate}.")
+        penguins = 0
+        for team in Team.all():
+            count = self.board.penguin_count(team)
+            if count > 4:
+                raise ValueError(f"Team {team} has {count} penguins, but only four are allowed.")
+            if self.fishes.get_fish_by_team(team) < count:
+                raise ValueError(f"Team {team} has collected {self.fishes.get_fish_by_team(team)} fish, "
+                                 f"but at least one for each of its {count} penguins.")
+            penguins += count
+        if self.turn < 0:
+            raise ValueError(f"The turn {self.turn} is negative.")
+        if self.round != int((self.turn + 1) / 2):
+            raise ValueError(f"The round {self.round} doesn't match the turn {self.turn}.")
+        if self.turn < penguins:
+            raise ValueError(f"{penguins} penguins have been placed in only {self.turn} turns.")
+        collected = self.fishes.fishes_one + self.fishes.fishes_two
+        if collected > 4 * self.turn:
+            raise ValueError(f"{collected} fish have been collected in {self.turn} turns, "
+                             f"but at most four per turn are possible.")
+        fields = self.board.width() * self.board.height()
+        on_board = sum(field.get_fish() or 0 for row in self.board._game_field for field in row)
+        if collected + on_board > 4 * fields:
+            raise ValueError(f"{collected} fish have been collected and {on_board} are on the board, "
+                             f"but the {fields} fields can only hold {4 * fields}.")
+
+    def clone_with_board(self, board: Board) -> 'GameState':
+        """
+        Copies the game state with another board.
+        The turn, the start team, the fish and the last move stay the same,
+        while the current team and the possible moves are calculated for the new board.
+
+        :param board: The new board.
+        :return: The new game state.
+        """
+        return GameState(board=board, turn=self.turn, start_team=self.start_team, fishes=self.fishes,
+                         last_move=self.last_move)
+
+    def canonical(self) -> 'GameState':
+        """
+        Copies the game state with the `Board.canonical` board.
+        The symmetry doesn't swap the teams, so the turn, the start team and the fish stay the same,
+        and so does the team to move. The last move is mapped along with the board.
+
+        :return: The game state, which is the same for a game state and its symmetric game state.
+        """
+        board = self.board.canonical()
+        last_move = self.last_move
+        if last_move and board != self.board:
+            from_value = self.board._symmetric_coordinate(last_move.from_value) if last_move.from_value else None
+            last_move = Move(to_value=self.board._symmetric_coordinate(last_move.to_value), from_value=from_value)
+        return GameState(board=board, turn=self.turn, start_team=self.start_team, fishes=self.fishes,
+                         last_move=last_move)
+
+    def with_history(self) -> 'GameState':
+        """
+        Copies the game state with an empty history, to which `perform_move` appends every performed move.
+        Without calling this, the `history` is None.
+
+        :return: The game state, whose `history` is an empty list.
+        """
+        state = self.clone_with_board(self.board)
+        state._undo = self._undo
+        state._undo_length = self._undo_length
+        state._history_start = self._undo_length
+        return state
+
+    @property
+    def history(self) -> Optional[List[Move]]:
+        """
+        The moves performed with `perform_move` since `with_history` has been called, read from the moves,
+        that `undo` can revert.
+
+        :return: The performed moves in order, or None if `with_history` hasn't been called.
+        """
+        if self._history_start is None:
+            return None
+        moves = []
+        last_move, undo = self.last_move, self._undo
+        for _ in range(self._undo_length - self._history_start):
+            fish, previous_move, undo = undo
+            if fish is not None:
+                moves.append(last_move)
+            last_move = previous_move
+        return moves[::-1]
 
     def _get_possible_moves(self, current_team: Team = None) -> List[Move]:
         """
@@ -815,60 +1739,781 @@ class GameState:
         That includes all possible moves from all Fields that are not occupied by a penguin from that team.
 
         :param current_team: The team to get the possible moves for.
-        :return: A list of all possible moves from the current player's turn.
+        :return: A list of all possible moves from the current player's turn, without any duplicates.
         """
-        current_team = current_team or self.current_team
-        moves = []
-        if len(self.board.get_teams_penguins(current_team)) < 4:
-            for x in range(self.board.width()):
-                for y in range(self.board.height()):
-                    field = self.board.get_field(CartesianCoordinate(x, y).to_hex())
-                    if not field.is_occupied() and field.get_fish() == 1:
-                        moves.append(Move(from_value=None, to_value=CartesianCoordinate(x, y).to_hex()))
-        else:
-            for piece in self.board.get_teams_penguins(current_team):
-                moves.extend(self.board.possible_moves_from(piece))
-        return moves
+        return list(MoveIterator(self.board, current_team or self.current_team))
+
+    def iter_moves(self, team: Team = None) -> MoveIterator:
+        """
+        Gets the possible moves of a team lazily.
+
+        :param team: The team to get the possible moves for. Default is the current team.
+        :return: An iterator, that generates the possible moves on demand.
+        """
+        return MoveIterator(self.board, team or self.current_team)
+
+    def opponent_possible_moves(self) -> List[Move]:
+        """
+        Gets all possible moves of the opponent of the current team.
+
+        :return: A list of all possible moves of the other team.
+        """
+        return self._get_possible_moves(self.other_team)
+
+    def possible_moves_from_penguin(self, position: HexCoordinate) -> List[Move]:
+        """
+        Gets all possible moves of one penguin of the current team.
+
+        :param position: The position of the penguin.
+        :return: A list of all possible moves that start at the given position.
+        :raise ValueError: If there is no penguin of the current team on the given position.
+        """
+        if position not in self.current_pieces:
+            raise ValueError(f"There is no penguin of team {self.current_team} on {position}.")
+        return [move for move in self.possible_moves if move.from_value == position]
+
+    def possible_moves_as_tuples(self) -> List[Tuple[Optional[Tuple[int, int]], Tuple[int, int]]]:
+        """
+        Gets the possible moves as plain tuples of hex coordinates.
+
+        :return: A tuple `(from, to)` for each of the `possible_moves`, in the same order,
+                 where `from` and `to` are `(x, y)` tuples and `from` is None for placements.
+        """
+        return [((move.from_value.x, move.from_value.y) if move.from_value else None,
+                 (move.to_value.x, move.to_value.y)) for move in self.possible_moves]
+
+    def legal_destinations(self, position: HexCoordinate) -> List[HexCoordinate]:
+        """
+        Gets only the destinations of the possible moves of one penguin of the current team.
+
+        :param position: The position of the penguin.
+        :return: A list of the coordinates, that the penguin can slide to.
+        :raise ValueError: If there is no penguin of the current team on the given position.
+        """
+        if position not in self.current_pieces:
+            raise ValueError(f"There is no penguin of team {self.current_team} on {position}.")
+        return [move.to_value for move in self.possible_moves if move.from_value == position]
 
     def current_team_from_turn(self) -> Team:
         """
         Calculates the current team from the turn number.
+        If the team, whose turn it is by the turn number, can't move, the other team moves instead.
+        If both teams can't move, the game is over and the team by the turn number is returned,
+        without any move being possible.
 
         :return: The team that has the current turn.
         """
-        current_team_by_turn = self.start_team if self.turn % 2 == 0 else self.start_team.opponent()
-        if not self._get_possible_moves(current_team_by_turn):
+        current_team_by_turn = self._team_by_turn()
+        if not self._get_possible_moves(current_team_by_turn) and \
+                self._get_possible_moves(current_team_by_turn.opponent()):
             return current_team_by_turn.opponent()
         return current_team_by_turn
 
+    def _team_by_turn(self) -> Team:
+        """
+        :return: The team, whose turn it is by the turn number, no matter if it can move.
+        """
+        return self.start_team if self.turn % 2 == 0 else self.start_team.opponent()
+
+    def is_first_turn(self) -> bool:
+        """
+        :return: True if no move has been made yet, False otherwise.
+        """
+        return self.turn == 0
+
+    def half_of_round(self) -> int:
+        """
+        Calculates in which half of the current round the turn is.
+        The first turn 0 is round 0 on its own, after that every round consists of an odd and the following even turn,
+        e.g. turn 1 and 2 are round 1.
+
+        :return: 0 if the turn is the first half of the round, 1 if it is the second half.
+        """
+        return (self.turn + 1) % 2
+
+    def is_first_move(self) -> bool:
+        """
+        The same as `is_first_turn`. In the first move the start team places the first penguin,
+        which is not necessarily team ONE. So a client should not decide by its team whether to move first,
+        but wait for the move request of the server and check `is_my_turn` with its assigned team.
+
+        :return: True in turn 0, False otherwise.
+        """
+        return self.is_first_turn()
+
+    def is_my_turn(self, me: Team) -> bool:
+        """
+        Checks whether it is the given team's turn.
+        The start team changes between games, so team ONE does not necessarily make the first move.
+        In the first move, see `is_first_move`, it is the start team's turn.
+
+        :param me: The own team.
+        :return: True if the team is the current team, False otherwise.
+        """
+        return self.current_team == me
+
+    def penguin_count(self, team: Team) -> int:
+        """
+        :param team: The team to count the penguins of.
+        :return: The amount of penguins the team has placed on the board.
+        """
+        return self.board.penguin_count(team)
+
+    def placements_remaining(self, team: Team) -> int:
+        """
+        :param team: The team to count the placements of.
+        :return: The amount of penguins the team still has to place, 0 after the placement phase.
+        """
+        return max(0, 4 - self.penguin_count(team))
+
+    def in_placement_phase(self) -> bool:
+        """
+        The game starts with the placement phase, in which both teams place their four penguins
+        on fields with one fish alternately.
+
+        :return: True while any team has less than four penguins on the board, False otherwise.
+        """
+        return self.board.penguin_count(Team("ONE")) < 4 or self.board.penguin_count(Team("TWO")) < 4
+
+    def phase(self) -> GamePhase:
+        """
+        :return: The phase of the game, that is `GamePhase.FINISHED` as soon as the game is over,
+                 even if not all penguins have been placed.
+        """
+        if self.is_over():
+            return GamePhase.FINISHED
+        return GamePhase.PLACEMENT if self.in_placement_phase() else GamePhase.MOVEMENT
+
+    def turns_remaining(self) -> int:
+        """
+        Calculates how many turns can be played at most, until the game is over.
+        Every turn takes one field from the board, either by placing a penguin on it or by leaving it,
+        so a game can't last longer than the board has fields.
+
+        :return: The maximum amount of remaining turns.
+        """
+        return max(0, self.board.width() * self.board.height() - self.turn)
+
+    def turns_remaining_estimate(self) -> int:
+        """
+        Estimates how many turns can be played at most, until the game is over.
+        Every move ends on an unoccupied field with fish, which is gone afterwards.
+        During the placement phase, all these fields count, since the penguins yet to be placed could reach them.
+        After the placement phase, only the fields count, that any penguin can reach with some moves.
+        So the estimate is closer than `turns_remaining` and decreases with every move.
+
+        :return: An upper bound of the remaining turns.
+        """
+        if self.is_over():
+            return 0
+        if self.in_placement_phase():
+            fields = sum(len(self.board.fields_with_fish(value)) for value in range(1, 5))
+        else:
+            fields = len(set().union(*(self._reachable_fields(self.board, team) for team in Team.all())))
+        return min(fields, self.turns_remaining())
+
+    def rounds_remaining(self) -> int:
+        """
+        Calculates how many rounds can be played at most after the current one, until the game is over.
+
+        :return: The maximum amount of remaining rounds.
+        """
+        last_round = int((self.board.width() * self.board.height() + 1) / 2)
+        return max(0, last_round - self.round)
+
+    def is_over(self) -> bool:
+        """
+        The game is over, when no team can move anymore.
+        If only the current team by turn is stuck, the other team moves instead, so then there are no possible moves.
+
+        :return: True if the game is over, False otherwise.
+        """
+        return not self.possible_moves
+
+    def winner(self) -> Optional[Team]:
+        """
+        :return: The team with the most fish, if the game is over, or None if the game is not over or a draw.
+        """
+        if not self.is_over() or self.fishes.fishes_one == self.fishes.fishes_two:
+            return None
+        return Team("ONE") if self.fishes.fishes_one > self.fishes.fishes_two else Team("TWO")
+
+    def is_draw(self) -> bool:
+        """
+        :return: True if the game is over and both teams have collected the same amount of fish, False otherwise.
+        """
+        return self.is_over() and self.fishes.fishes_one == self.fishes.fishes_two
+
+    def evaluate(self, team: Team) -> float:
+        """
+        A baseline evaluation of the game state from the view of the given team, that works out of the box.
+        It adds the fish advantage times `fish_weight` (1.0) and the advantage in possible moves
+        times `mobility_weight` (0.1). The weights can be changed in a subclass, or the evaluation can be combined
+        with other heuristics. The game state is not changed.
+
+        :param team: The team to evaluate the game state for.
+        :return: The evaluation, the higher the better for the team.
+        """
+        mobility = len(self._get_possible_moves(team)) - len(self._get_possible_moves(team.opponent()))
+        return self.fish_weight * self.fish_advantage(team) + self.mobility_weight * mobility
+
+    def advantage_sign(self, team: Team) -> int:
+        """
+        A quick readout of which team is winning, without the weights of `evaluate`.
+        The fish advantage decides, and if both teams have collected the same amount of fish,
+        the team with more possible moves is ahead.
+
+        :param team: The team to check the advantage for.
+        :return: 1 if the team is ahead, -1 if it is behind and 0 if the game state is even.
+        """
+        advantage = self.fish_advantage(team)
+        if advantage == 0:
+            advantage = len(self._get_possible_moves(team)) - len(self._get_possible_moves(team.opponent()))
+        return (advantage > 0) - (advantage < 0)
+
+    def contested_fields(self) -> List[HexCoordinate]:
+        """
+        Searches for the fields with fish, that both teams can reach with the same least amount of moves.
+        Since no team reaches them first, they are often decisive.
+
+        :return: A list of the coordinates of the contested fields, row by row from the top.
+        """
+        distances = []
+        for team in Team.all():
+            team_distances = {}
+            for penguin in self.board.get_teams_penguins(team):
+                for position, distance in self.board.fish_distance_map(penguin):
+                    team_distances[position] = min(distance, team_distances.get(position, distance))
+            distances.append(team_distances)
+        contested = [position for position, distance in distances[0].items() if distances[1].get(position) == distance]
+        return sorted(contested, key=lambda position: (position.y, position.x))
+
+    def mobility(self, team: Team) -> List[Tuple[HexCoordinate, int]]:
+        """
+        Counts the sliding moves of each penguin of the given team.
+
+        :param team: The team of the penguins.
+        :return: The position of every penguin of the team with the amount of its moves, 0 for trapped penguins.
+        """
+        return [(penguin, len(self.board.possible_moves_from(penguin)))
+                for penguin in self.board.get_teams_penguins(team)]
+
+    def threats_against(self, team: Team) -> List[HexCoordinate]:
+        """
+        Searches the moves of the opponent, that cut the given team off from fish in the next turn,
+        by blocking a penguin or dividing its region. Taking only the fish on the destination of the move is no threat.
+
+        :param team: The team, that is threatened.
+        :return: A list of the destinations of the threatening moves, row by row from the top.
+        """
+        opponent = team.opponent()
+        reachable = self._reachable_fields(self.board, team)
+        threats = set()
+        for move in self._get_possible_moves(opponent):
+            board = self.board.simulate_move(move, opponent)
+            lost = reachable - self._reachable_fields(board, team) - {move.to_value}
+            if sum(self.board.get_fish_or_zero(position) for position in lost) > 0:
+                threats.add(move.to_value)
+        return sorted(threats, key=lambda position: (position.y, position.x))
+
+    @staticmethod
+    def _reachable_fields(board: Board, team: Team) -> set:
+        """
+        Collects the fields, that any penguin of the team can reach on the board with some moves.
+        """
+        return {position for penguin in board.get_teams_penguins(team)
+                for position, _ in board.fish_distance_map(penguin)}
+
+    def fish_advantage(self, team: Team) -> int:
+        """
+        Calculates how many more fish the given team has collected than its opponent.
+
+        :param team: The team to calculate the advantage for.
+        :return: The difference of the fish, negative if the team is trailing.
+        """
+        return self.fishes.get_fish_by_team(team) - self.fishes.get_fish_by_team(team.opponent())
+
+    def make_move(self, from_value: Optional[HexCoordinate], to_value: HexCoordinate) -> Move:
+        """
+        Creates a move of the current team and checks that it is valid.
+
+        :param from_value: The origin of the move, None for placements.
+        :param to_value: The destination of the move.
+        :return: The valid move.
+        :raise ValueError: If the move is not valid for the current team, with the reason in the message.
+        """
+        move = Move(to_value=to_value, from_value=from_value)
+        if self.is_valid_move(move):
+            return move
+        if not self.board.is_valid(to_value):
+            reason = f"{to_value} is not on the board"
+        elif from_value is None and not self.placements_remaining(self.current_team):
+            reason = "all penguins have been placed already"
+        elif from_value is not None and self.placements_remaining(self.current_team):
+            reason = "not all penguins have been placed yet"
+        elif from_value is None:
+            reason = "penguins can only be placed on unoccupied fields with one fish"
+        elif from_value not in self.current_pieces:
+            reason = f"there is no penguin of team {self.current_team} on {from_value}"
+        else:
+            reason = f"{to_value} can't be reached"
+        raise ValueError(f"Invalid move for team {self.current_team}: {move}, because {reason}.")
+
     def perform_move(self, move: Move) -> 'GameState':
         """
         Performs the given move on the current game state.
 
         :param move: The move to perform.
         :return: The new game state after the move has been performed.
+        :raise GameOverError: If the game is over, so that no team can move anymore.
         """
+        if self.is_over():
+            raise GameOverError(f"Cannot perform {move} in a finished game.")
         if self.is_valid_move(move):
-            new_board = self.board._move(move)
-            adding_fish = new_board.get_field(move.to_value).get_fish()
+            adding_fish = self.board.get_field(move.to_value).get_fish()
+            new_board = self.board.simulate_move(move, self.current_team)
             new_fishes_one = self.fishes.fishes_one + adding_fish if self.current_team == Team("ONE") else \
                 self.fishes.fishes_one
             new_fishes_two = self.fishes.fishes_two + adding_fish if self.current_team == Team("TWO") else \
                 self.fishes.fishes_two
             new_fishes = Fishes(new_fishes_one, new_fishes_two)
-            return GameState(board=new_board, turn=self.turn + 1, start_team=self.start_team, fishes=new_fishes,
-                             last_move=move)
+            new_state = GameState(board=new_board, turn=self.turn + 1, start_team=self.start_team, fishes=new_fishes,
+                                  last_move=move)
+            new_state._undo = (adding_fish, self.last_move, self._undo)
+            new_state._undo_length = self._undo_length + 1
+            new_state._history_start = self._history_start
+            if self._zobrist is not None:
+                new_state._zobrist = self._updated_zobrist(move, new_state)
+            return new_state
         logging.error(f"Performed invalid move while simulating: {move}")
         raise Exception(f"Invalid move: {move}")
 
+    def perform_move_index(self, index: int, team: Team = None) -> 'GameState':
+        """
+        Performs the move with the given index in `possible_moves`.
+
+        :param index: The index of the move.
+        :param team: If set, the team, that is expected to move. Only the current team can move.
+        :return: The new game state after the move has been performed.
+        :raise IndexError: If there is no possible move with the index.
+        :raise ValueError: If the given team is not the current team.
+        """
+        if team and team != self.current_team:
+            raise ValueError(f"It is the turn of team {self.current_team}, not of team {team}.")
+        if not 0 <= index < len(self.possible_moves):
+            raise IndexError(f"There are {len(self.possible_moves)} possible moves, but the index is {index}.")
+        return self.perform_move(self.possible_moves[index])
+
+    def perform_null_move(self) -> 'GameState':
+        """
+        Passes the turn, if the team, whose turn it is by the turn number, can't move, while the other team can.
+        The side to move is the team by the turn number, so it flips with the pass, while `current_team` already
+        skips the blocked team and is the other team before and after the pass.
+        No penguin is moved and no fish are collected, only the turn number is incremented.
+        The new game state gets a copy of the board. Like a performed move, the pass can be reverted with `undo`.
+
+        :return: The new game state after the turn has been passed.
+        :raise ValueError: If the team, whose turn it is, can move, or if the game is over.
+        """
+        team_by_turn = self._team_by_turn()
+        if self._get_possible_moves(team_by_turn):
+            raise ValueError(f"Team {team_by_turn} can move, so it can't pass the turn.")
+        if self.is_over():
+            raise ValueError("The game is over, so no team can pass the turn.")
+        passed = GameState(board=self.board._copy(), turn=self.turn + 1, start_team=self.start_team,
+                           fishes=self.fishes, last_move=self.last_move)
+        passed._undo = (None, self.last_move, self._undo)
+        passed._undo_length = self._undo_length + 1
+        passed._history_start = self._history_start
+        if self._zobrist is not None:
+            passed._zobrist = self._zobrist ^ (_zobrist_side_key if passed.current_team != self.current_team else 0)
+        return passed
+
+    def fish_gained_by(self, move: Move) -> int:
+        """
+        Previews the fish, that the current team would collect with the given move, without performing it.
+        The move isn't validated, so this should only be called with a possible move.
+
+        :param move: The move to preview.
+        :return: The amount of fish on the destination of the move.
+        """
+        return self.board.get_fish_or_zero(move.to_value)
+
+    def most_valuable_move(self) -> Optional[Move]:
+        """
+        Searches the possible move, that collects the most fish in this turn, like a greedy player would.
+        If several moves collect the same amount of fish, the first of them in `possible_moves` is chosen.
+
+        :return: The move with the most fish on its destination, or None if there are no possible moves.
+        """
+        if not self.possible_moves:
+            return None
+        return max(self.possible_moves, key=self.fish_gained_by)
+
+    def best_greedy_move(self) -> Optional[Move]:
+        """
+        The same as `most_valuable_move`, under the name the tutorial uses for a greedy player.
+
+        :return: The move with the most fish on its destination, or None if there are no possible moves.
+        """
+        return self.most_valuable_move()
+
+    def book_move(self, book: 'OpeningBook') -> Optional[Move]:
+        """
+        Looks up the position in an opening book.
+        Since the book matches positions by a hash, the move is checked to be possible in this game state.
+
+        :param book: The opening book.
+        :return: The move of the book for this position, or None if the book has no possible move for it.
+        """
+        move = book.get(self)
+        return move if move and self.is_valid_move(move) else None
+
+    def perform_random_move(self, seed: int = None) -> Optional['GameState']:
+        """
+        Performs a uniformly random possible move.
+
+        :param seed: If set, the random move is the same for every call with the same seed.
+        :return: The new game state after the move has been performed, or None if there are no possible moves.
+        """
+        if not self.possible_moves:
+            return None
+        return self.perform_move(random.Random(seed).choice(self.possible_moves))
+
+    def simulate_random_game(self, seed: int = None) -> 'GameState':
+        """
+        Performs uniformly random possible moves until the game is over.
+        A team, that can't move, doesn't have to pass, since the other team moves instead.
+
+        :param seed: If set, the game is the same for every call with the same seed.
+        :return: The game state at the end of the game.
+        """
+        rng = random.Random(seed)
+        state = self
+        while state.possible_moves:
+            state = state.perform_move(rng.choice(state.possible_moves))
+        return state
+
+    def random_playout(self, seed: int = None) -> Optional[Team]:
+        """
+        Plays a random game with `simulate_random_game` and only keeps the winner.
+
+        :param seed: If set, the playout is the same for every call with the same seed.
+        :return: The winner of the playout, or None if it ended in a draw.
+        """
+        return self.simulate_random_game(seed).winner()
+
+    def last_move_fish_gain(self) -> Optional[int]:
+        """
+        Looks up how many fish the team, that made the last move, has collected by sliding.
+        The fish of sliding moves are only known, if the move has been performed with `perform_move`.
+
+        :return: The amount of collected fish, 0 if the last move is a placement,
+                 or None if there is no last move or the amount is unknown.
+        """
+        if not self.last_move:
+            return None
+        if not self.last_move.from_value:
+            return 0
+        undo = self._undo
+        while undo and undo[0] is None:
+            undo = undo[2]
+        return undo[0] if undo else None
+
+    def undo(self) -> 'GameState':
+        """
+        Reverts the last move, if it has been performed with `perform_move`.
+        The penguin is moved back, the collected fish are put back on the field and the turn is decremented.
+        A turn passed with `perform_null_move` is reverted by only decrementing the turn.
+        This can be repeated for all moves, that have been performed one after another.
+
+        :return: The game state before the last move.
+        :raise ValueError: If there is no performed move to undo, e.g. in a game state that the server has sent.
+        """
+        if not self._undo:
+            raise ValueError("There is no performed move to undo.")
+        fish, last_move, undo = self._undo
+        # undoing a move from before with_history has been called keeps the history empty
+        history_start = None if self._history_start is None else min(self._history_start, self._undo_length - 1)
+        if fish is None:
+            previous_state = GameState(board=self.board._copy(), turn=self.turn - 1, start_team=self.start_team,
+                                       fishes=self.fishes, last_move=last_move)
+            previous_state._undo = undo
+            previous_state._undo_length = self._undo_length - 1
+            previous_state._history_start = history_start
+            return previous_state
+        team = self.board.get_field(self.last_move.to_value).get_team()
+        board = self.board._copy()
+        board._set_field(self.last_move.to_value, fish)
+        if self.last_move.from_value:
+            board._set_field(self.last_move.from_value, team)
+        fishes_one = self.fishes.fishes_one - fish if team == Team("ONE") else self.fishes.fishes_one
+        fishes_two = self.fishes.fishes_two - fish if team == Team("TWO") else self.fishes.fishes_two
+        previous_state = GameState(board=board, turn=self.turn - 1, start_team=self.start_team,
+                                   fishes=Fishes(fishes_one, fishes_two), last_move=last_move)
+        previous_state._undo = undo
+        previous_state._undo_length = self._undo_length - 1
+        previous_state._history_start = history_start
+        return previous_state
+
     def is_valid_move(self, move: Move) -> bool:
         """
         Checks if the given move is valid.
+        Most invalid moves are rejected without searching the possible moves,
+        e.g. if no penguin of the current team is on the origin, or the destination is not in line with it.
         
         :param move: The move to check.
         :return: True if the move is valid, False otherwise.
         """
+        if not isinstance(move, Move) or not move.to_value or not self.board.is_valid(move.to_value):
+            return False
+        if move.from_value:
+            d_x = move.to_value.x - move.from_value.x
+            d_y = move.to_value.y - move.from_value.y
+            in_line = abs(d_x) == abs(d_y) if d_y else d_x % 2 == 0
+            if not d_x or not in_line or move.from_value not in self.current_pieces:
+                return False
+        elif self.board.get_fish_or_zero(move.to_value) != 1:
+            return False
         for possible_move in self.possible_moves:
             if possible_move == move:
                 return True
         return False
+
+    def to_dict(self) -> dict:
+        """
+        Converts the game state to a dictionary, that can be serialized to json.
+        The board is stored in the compact form of `Board.to_strings`.
+
+        :return: The game state as a dictionary.
+        """
+        return {
+            "turn": self.turn,
+            "round": self.round,
+            "start_team": str(self.start_team),
+            "current_team": str(self.current_team),
+            "fishes": self.fishes.to_dict(),
+            "last_move": self.last_move.to_dict() if self.last_move else None,
+            "board": self.board.to_strings(),
+        }
+
+    def to_json(self) -> str:
+        """
+        Serializes the game state to json. The form is stable, the keys are sorted and indented by two spaces.
+
+        :return: The game state as json.
+        """
+        return json.dumps(self.to_dict(), indent=2, sort_keys=True)
+
+    def to_bytes(self) -> bytes:
+        """
+        Serializes the game state to a compact binary form.
+        A header of 13 bytes with the width and height of the board, the turn, the start team, the fish
+        and the last move is followed by one byte per field, row by row: 0 to 4 fish, 5 for a penguin of team one
+        and 6 for a penguin of team two. The 8x8 board of the game takes 77 bytes.
+
+        :return: The game state as bytes, that `from_bytes` reads.
+        """
+        from_value = self.last_move.from_value if self.last_move and self.last_move.from_value else None
+        to_value = self.last_move.to_value if self.last_move else None
+        header = self._bytes_header.pack(self.board.width(), self.board.height(), self.turn,
+                                         Team.all().index(self.start_team), self.fishes.fishes_one,
+                                         self.fishes.fishes_two, from_value.x if from_value else -1,
+                                         from_value.y if from_value else -1, to_value.x if to_value else -1,
+                                         to_value.y if to_value else -1)
+        fields = bytes(5 + Team.all().index(field.field) if field.is_occupied() else field.field
+                       for row in self.board._game_field for field in row)
+        return header + fields
+
+    @staticmethod
+    def from_bytes(data: bytes) -> 'GameState':
+        """
+        Deserializes a game state, that `to_bytes` has serialized.
+
+        :param data: The bytes of the game state.
+        :return: The game state.
+        :raise ValueError: If the data is truncated or contains an invalid field.
+        """
+        header = GameState._bytes_header
+        if len(data) < header.size:
+            raise ValueError(f"The data has {len(data)} bytes, but the header alone has {header.size} bytes.")
+        width, height, turn, start_team, fishes_one, fishes_two, from_x, from_y, to_x, to_y = \
+            header.unpack_from(data)
+        if len(data) != header.size + width * height:
+            raise ValueError(f"The data of a {width}x{height} board has to have {header.size + width * height} "
+                             f"bytes, but it has {len(data)} bytes.")
+        if start_team > 1 or any(value > 6 for value in data[header.size:]):
+            raise ValueError("The data contains an invalid team or field.")
+        rows = []
+        for y in range(height):
+            values = data[header.size + y * width:header.size + (y + 1) * width]
+            rows.append([Field(CartesianCoordinate(x, y).to_hex(), value if value <= 4 else Team.all()[value - 5])
+                         for x, value in enumerate(values)])
+        last_move = None
+        if to_x >= 0:
+            from_value = HexCoordinate(from_x, from_y) if from_x >= 0 else None
+            last_move = Move(to_value=HexCoordinate(to_x, to_y), from_value=from_value)
+        return GameState(board=Board(rows), turn=turn, start_team=Team.all()[start_team],
+                         fishes=Fishes(fishes_one, fishes_two), last_move=last_move)
+
+    def to_feature_array(self) -> List[List[List[float]]]:
+        """
+        Converts the game state to planes of numbers.
+        Every plane has one list per row of the board with one number per field, like the board itself.
+        The planes are in this order:
+
+            - 0: 1.0 on the fields of the penguins of the current team
+            - 1: 1.0 on the fields of the penguins of the other team
+            - 2 to 5: 1.0 on the fields with 1 to 4 fish
+            - 6: 1.0 on all fields if team ONE is the current team, 0.0 if it is team TWO
+
+        :return: The seven planes.
+        """
+        side_to_move = 1.0 if self.current_team == Team("ONE") else 0.0
+        planes = [[], [], [], [], [], [], []]
+        for row in self.board._game_field:
+            for plane in planes:
+                plane.append([])
+            for field in row:
+                values = [0.0] * 7
+                if field.get_team() == self.current_team:
+                    values[0] = 1.0
+                elif field.get_team() == self.other_team:
+                    values[1] = 1.0
+                elif field.get_fish():
+                    values[1 + field.get_fish()] = 1.0
+                values[6] = side_to_move
+                for plane, value in zip(planes, values):
+                    plane[-1].append(value)
+        return planes
+
+    def zobrist_hash(self) -> int:
+        """
+        Calculates a 64 bit zobrist hash of the penguins, the fish on the board and the team to move.
+        Unlike `__hash__`, it ignores the turn and the collected fish,
+        so that the same position reached by different move orders has the same hash.
+        Once calculated, the hash is updated by `perform_move` with a few xor operations instead of a full scan.
+
+        :return: The zobrist hash of the game state.
+        """
+        if self._zobrist is None:
+            zobrist = _zobrist_side_key if self.current_team == Team("TWO") else 0
+            for y, row in enumerate(self.board._game_field):
+                for x, field in enumerate(row):
+                    zobrist ^= _zobrist_key(y * len(row) + x, field.field)
+            self._zobrist = zobrist
+        return self._zobrist
+
+    def _updated_zobrist(self, move: Move, new_state: 'GameState') -> int:
+        """
+        Updates the zobrist hash of this game state with the fields, that the move changes.
+        """
+        width = self.board.width()
+        to_index = move.to_value.to_index(width)
+        zobrist = self._zobrist ^ _zobrist_key(to_index, self.board.get_field(move.to_value).field) ^ \
+            _zobrist_key(to_index, self.current_team)
+        if move.from_value:
+            from_index = move.from_value.to_index(width)
+            zobrist ^= _zobrist_key(from_index, self.current_team) ^ _zobrist_key(from_index, 0)
+        if self.current_team != new_state.current_team:
+            zobrist ^= _zobrist_side_key
+        return zobrist
+
+    def equal_ignoring_history(self, other: 'GameState') -> bool:
+        """
+        Compares the position of two game states.
+        Unlike `__eq__`, the last move is ignored, while the current teams are compared.
+        Like `__eq__`, the `history` and the moves, that `undo` could revert, are not compared.
+
+        :param other: The game state to compare to.
+        :return: True if the boards, the turns, the start teams, the fish and the current teams are equal.
+        """
+        return self.turn == other.turn and self.start_team == other.start_team and self.fishes == other.fishes and \
+            self.current_team == other.current_team and self.board == other.board
+
+    def __eq__(self, __o: object) -> bool:
+        return isinstance(__o, GameState) and self.turn == __o.turn and self.start_team == __o.start_team and \
+            self.fishes == __o.fishes and self.last_move == __o.last_move and self.board == __o.board
+
+    def __hash__(self) -> int:
+        return hash((self.turn, str(self.start_team), self.fishes.fishes_one, self.fishes.fishes_two,
+                     self.board.structural_hash()))
+
+    def _order_key(self) -> Tuple[int, int]:
+        """
+        The key game states are ordered by: the turn first, then the fish advantage of the start team.
+        The advantage is always taken from the start team's view, so that states of the same game stay comparable.
+        """
+        return self.turn, self.fish_advantage(self.start_team)
+
+    def __lt__(self, __o: object) -> bool:
+        """
+        Orders game states by turn and then by the fish advantage of the start team.
+        Note that this ordering is independent of `__eq__`: states on different boards can be neither
+        lower nor greater than each other without being equal, while `<=` and `>=` are both true for them.
+        """
+        if not isinstance(__o, GameState):
+            return NotImplemented
+        return self._order_key() < __o._order_key()
+
+    def __le__(self, __o: object) -> bool:
+        if not isinstance(__o, GameState):
+            return NotImplemented
+        return self._order_key() <= __o._order_key()
+
+    def __gt__(self, __o: object) -> bool:
+        if not isinstance(__o, GameState):
+            return NotImplemented
+        return self._order_key() > __o._order_key()
+
+    def __ge__(self, __o: object) -> bool:
+        if not isinstance(__o, GameState):
+            return NotImplemented
+        return self._order_key() >= __o._order_key()
+
+    def __repr__(self) -> str:
+        return f"GameState(turn={self.turn}, round={self.round}, current_team={self.current_team}, " \
+               f"fishes_one={self.fishes.fishes_one}, fishes_two={self.fishes.fishes_two}, last_move={self.last_move})"
+
+
+class OpeningBook:
+    """
+    Maps positions to the preferred moves in them.
+    The positions are looked up by their `GameState.zobrist_hash`, so the same position is found,
+    no matter by which order of moves it has been reached.
+    """
+
+    def __init__(self):
+        self._moves: Dict[int, Move] = {}
+
+    def add(self, state: GameState, move: Move):
+        """
+        Registers the move for the position of the game state, replacing a previously registered move.
+
+        :param state: The game state of the position.
+        :param move: The preferred move in the position.
+        :raise ValueError: If the move is not possible in the game state.
+        """
+        if not state.is_valid_move(move):
+            raise ValueError(f"The move {move} is not possible for team {state.current_team} in turn {state.turn}.")
+        self._moves[state.zobrist_hash()] = move
+
+    def get(self, state: GameState) -> Optional[Move]:
+        """
+        :param state: The game state to look up.
+        :return: The move registered for the position, or None if there is none.
+        """
+        return self._moves.get(state.zobrist_hash())
+
+    def __len__(self) -> int:
+        return len(self._moves)
+
+
+def batch_possible_moves(states: List[GameState]) -> List[List[Move]]:
+    """
+    Gets the possible moves of many game states at once.
+
+    :param states: The game states.
+    :return: The possible moves of the current team for each game state, in the same order as the game states.
+    """
+    return [state.possible_moves for state in states]
@@ -785,6 +785,50 @@ class Result(ObservableRoomMessage):
     scores: Scores
     winner: Winner
 
+    def get_winner(self) -> Optional[Team]:
+        """
+        :return: The team that has won the game, or None if the game ended in a draw.
+        """
+        if not self.winner or not self.winner.team:
+            return None
+        return Team(self.winner.team)
+
+    def get_score(self, team: Team) -> Optional[Score]:
+        """
+        Looks up the final score of a team.
+        The parts of a score are the victory points and the collected fish, in the order of the definition fragments.
+
+        :param team: The team to get the score of.
+        :return: The score of the team, or None if the server sent no score for it.
+        """
+        for entry in self.scores.entry if self.scores else []:
+            if entry.player and entry.player.team == team.color():
+                return entry.score
+        return None
+
+    def get_fishes(self, team: Team) -> Optional[int]:
+        """
+        :param team: The team to get the fish of.
+        :return: The amount of fish the team has collected, or None if the server sent no score for it.
+        """
+        score = self.get_score(team)
+        if not score or len(score.part) < 2:
+            return None
+        return score.part[1]
+
+    def get_cause(self) -> str:
+        """
+        Looks up why the game has ended.
+        This is REGULAR if the game was played to the end, otherwise it is the cause of the team,
+        that ended the game early, e.g. LEFT or RULE_VIOLATION.
+
+        :return: The cause of the end of the game.
+        """
+        for entry in self.scores.entry if self.scores else []:
+            if entry.score and entry.score.cause and entry.score.cause != "REGULAR":
+                return entry.score.cause
+        return "REGULAR"
+
 
 @dataclass
 class Error:
@@ -4,8 +4,16 @@ This is the main entry point for the SoCha application.
 import argparse
 import datetime
 import logging
+import socket
 
-from socha.api.networking.player_client import _PlayerClient, IClientHandler
+from socha.api.networking.player_client import _PlayerClient, IClientHandler, _dry_run, _read_resume_token
+from socha.api.plugin.penguins import GameState, Team
+
+
+class SochaConnectionError(Exception):
+    """
+    Raised by the `Starter`, if the client can't connect to the given host and port.
+    """
 
 
 class Starter:
@@ -16,7 +24,10 @@ class Starter:
     """
 
     def __init__(self, logic: IClientHandler, host: str = "localhost", port: int = 13050, reservation: str = None,
-                 room_id: str = None, survive: bool = False, log: bool = False, verbose: bool = False):
+                 room_id: str = None, survive: bool = False, log: bool = False, verbose: bool = False,
+                 keep_alive: int = None, record_file: str = None, replay_file: str = None,
+                 dry_run_from: GameState = None, requested_color: Team = None, buffer_size: int = 16129,
+                 resume_token_path: str = None, strict: bool = False):
         """
         All these arguments can be overwritten, when parsed via start arguments,
         or you initialize this class with the desired values.
@@ -29,6 +40,19 @@ class Starter:
         :param survive: If True the client will keep running, even if the connection to the server is terminated.
         :param log: If True the client will write a log file to the current directory.
         :param verbose: Verbose option for logging.
+        :param keep_alive: If set, the connection is kept alive after this many seconds of idling.
+        :param record_file: If set, all data received from the server is recorded to this file.
+        :param replay_file: If set, this recorded file is replayed instead of connecting to a server.
+        :param dry_run_from: If set, the logic calculates a move for this state, which is checked against the rules,
+                             without connecting to a server.
+        :param requested_color: The team the client would like to play as.
+                                The server assigns the teams, so the client plays as the assigned team anyway.
+        :param buffer_size: Maximum amount of bytes, that are read from the connection at once.
+        :param resume_token_path: If set, the joined room is stored to this file, until the game is over.
+                                  If the file exists on start, the client tries to rejoin the room.
+                                  If the room can't be rejoined, the file is removed and a new game is joined.
+        :param strict: If True, every state from the server is compared to the previous state with the last move
+                       performed, and divergences are logged as warnings.
         """
         args = self._handle_start_args()
 
@@ -38,6 +62,13 @@ class Starter:
         self.room_id: str = args.room or room_id
         self.survive: bool = args.survive or survive
         self.write_log: bool = args.log or log
+        self.keep_alive: int = args.keep_alive or keep_alive
+        self.record_file: str = args.record or record_file
+        self.replay_file: str = args.replay or replay_file
+        self.requested_color: Team = Team(args.color) if args.color else requested_color
+        self.buffer_size: int = args.buffer_size or buffer_size
+        self.resume_token_path: str = args.resume_token or resume_token_path
+        self.strict: bool = args.strict or strict
 
         if args.verbose or verbose:
             level: int = logging.DEBUG
@@ -53,17 +84,49 @@ class Starter:
             logging.basicConfig(level=level, format="%(asctime)s: %(levelname)s - %(message)s")
         logging.info("Starting...")
 
-        self.client = _PlayerClient(host=self.host, port=self.port, handler=logic, survive=self.survive)
+        if dry_run_from:
+            _dry_run(logic, dry_run_from)
+            return
+        if not self.replay_file:
+            self._check_address(self.host, self.port)
+
+        self.client = _PlayerClient(host=self.host, port=self.port, handler=logic, survive=self.survive,
+                                    keep_alive=self.keep_alive, record_file=self.record_file,
+                                    replay_file=self.replay_file, requested_color=self.requested_color,
+                                    buffer_size=self.buffer_size, resume_token_path=self.resume_token_path,
+                                    strict=self.strict)
 
-        if reservation:
-            self.client.join_game_with_reservation(reservation)
-        elif room_id:
-            self.client.join_game_room(room_id)
+        resume_room_id = _read_resume_token(self.resume_token_path) if self.resume_token_path else None
+        if self.reservation:
+            self.client.join_game_with_reservation(self.reservation)
+        elif self.room_id:
+            self.client.join_game_room(self.room_id)
+        elif resume_room_id:
+            logging.info(f"Trying to rejoin room {resume_room_id} from the resume token {self.resume_token_path}.")
+            self.client.rejoin_game_room(resume_room_id)
         else:
             self.client.join_game()
 
         self.client.start()
 
+    @staticmethod
+    def _check_address(host: str, port: int):
+        """
+        Checks the port range and resolves the host, before the client connects,
+        so that a wrong address is reported clearly instead of deep inside the connection loop.
+
+        :param host: The host that the client should connect to.
+        :param port: The port of the host.
+        :raise SochaConnectionError: If the port is not between 1 and 65535, or if the host can't be resolved.
+        """
+        if not isinstance(port, int) or not 0 < port < 65536:
+            raise SochaConnectionError(f"Can't connect to {host}:{port}, the port has to be between 1 and 65535.")
+        try:
+            socket.getaddrinfo(host, port, type=socket.SOCK_STREAM)
+        except socket.gaierror as error:
+            raise SochaConnectionError(f"Can't connect to {host}:{port}, the host can't be resolved: "
+                                       f"{error.strerror}") from error
+
     @staticmethod
     def _handle_start_args():
         parser = argparse.ArgumentParser(description='All arguments are optional.', add_help=False,
@@ -80,4 +143,19 @@ class Starter:
         parser.add_argument('-l', '--log', action='store_true',
                             help='If present the client will write a log file to the current directory.')
         parser.add_argument('-v', '--verbose', action='store_true', help='Verbose option for logging.')
+        parser.add_argument('-k', '--keep-alive', type=int,
+                            help='If present the connection is kept alive after this many seconds of idling.')
+        parser.add_argument('--record', type=str, help='Records all data received from the server to this file.')
+        parser.add_argument('--replay', type=str,
+                            help='Replays this recorded file instead of connecting to a server.')
+        parser.add_argument('-c', '--color', choices=['ONE', 'TWO'],
+                            help='The team the client would like to play as. The server assigns the teams anyway.')
+        parser.add_argument('--buffer-size', type=int,
+                            help='Maximum amount of bytes, that are read from the connection at once. '
+                                 'The default is 16129.')
+        parser.add_argument('--resume-token', type=str,
+                            help='Stores the joined room to this file and rejoins it after a restart, '
+                                 'until the game is over.')
+        parser.add_argument('--strict', action='store_true',
+                            help='If present every state from the server is checked against the simulated one.')
         return parser.parse_args()
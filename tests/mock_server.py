@@ -0,0 +1,43 @@
+import socket
+import threading
+import time
+from typing import List
+
+
+class MockServer:
+    """
+    A game server stand-in, that accepts one client, idles for a while, sends the given frames
+    and records everything the client sends until it closes the connection.
+    """
+
+    def __init__(self, frames: List[bytes], idle: float = 0):
+        self.socket = socket.socket(socket.AF_INET, socket.SOCK_STREAM)
+        self.socket.bind(("localhost", 0))
+        self.socket.listen(1)
+        self.port = self.socket.getsockname()[1]
+        self.received = b""
+        self.thread = threading.Thread(target=self._serve, args=(frames, idle), daemon=True)
+        self.thread.start()
+
+    def _serve(self, frames: List[bytes], idle: float):
+        connection, _ = self.socket.accept()
+        with connection:
+            time.sleep(idle)
+            for frame in frames:
+                connection.sendall(frame)
+            connection.settimeout(2)
+            try:
+                while True:
+                    data = connection.recv(16129)
+                    if not data:
+                        break
+                    self.received += data
+            except (socket.timeout, ConnectionResetError):
+                pass
+        self.socket.close()
+
+    def join(self):
+        """
+        Waits until the client has closed the connection.
+        """
+        self.thread.join(timeout=5)
@@ -0,0 +1,95 @@
+import os
+import socket
+import tempfile
+import unittest
+from typing import List
+
+from socha.api.networking._network_interface import _NetworkInterface, _ReplayInterface
+from socha.api.networking._xflux import _XFlux, _XFluxClient
+from socha.api.networking.player_client import _convertState
+from socha.api.plugin.penguins import GameState
+from socha.api.protocol.protocol import Joined
+from tests.mock_server import MockServer
+from tests.test_xflux import memento
+
+
+class KeepAliveTest(unittest.TestCase):
+    def test_idle_connection(self):
+        server = MockServer([b'<joined roomId="b5a4f8c2"/>'], idle=1)
+        client = _XFluxClient("localhost", server.port, keep_alive=1)
+        client._network_interface.socket.settimeout(0.1)
+        self.assertTrue(client._network_interface.socket.getsockopt(socket.SOL_SOCKET, socket.SO_KEEPALIVE))
+
+        joined = client._receive()
+        self.assertTrue(client._network_interface.connected)
+        self.assertIsInstance(joined, Joined)
+        self.assertEqual(joined.room_id, "b5a4f8c2")
+
+        client.close_connection()
+        server.join()
+        self.assertTrue(server.received.startswith(b"<protocol><close/>"))
+
+    def test_disabled_by_default(self):
+        server = MockServer([])
+        client = _XFluxClient("localhost", server.port)
+        self.assertFalse(client._network_interface.socket.getsockopt(socket.SOL_SOCKET, socket.SO_KEEPALIVE))
+        client.close_connection()
+        server.join()
+
+
+class ChunkInterface(_NetworkInterface):
+    """
+    Receives the given chunks one after another instead of reading from a socket.
+    """
+
+    def __init__(self, chunks: List[bytes]):
+        super().__init__()
+        self.chunks = chunks
+
+    def receive_socket_data(self) -> bytes:
+        return self.chunks.pop(0)
+
+
+class FramingTest(unittest.TestCase):
+    def test_split_memento(self):
+        split = memento.index(b"</lastMove>")
+        self.assertIn(b"/>", memento[:split])
+        interface = ChunkInterface([b"<protocol>\n" + memento[:split], memento[split:]])
+        received = interface.receive()
+        self.assertEqual(received, memento)
+        self.assertFalse(interface.chunks)
+
+        game_state = _convertState(_XFlux().deserialize_object(received).data.class_binding)
+        self.assertIsInstance(game_state, GameState)
+        self.assertEqual(game_state.turn, 2)
+
+    def test_small_buffer(self):
+        frames = [memento, b'<joined roomId="b5a4f8c2"/>',
+                  b'<room roomId="b5a4f8c2"><data class="moveRequest"/></room>']
+        server = MockServer([b'<?xml version="1.0" encoding="UTF-8"?>\n<protocol>\n' + b"\n".join(frames)])
+        interface = _NetworkInterface("localhost", server.port, buffer_size=64)
+        interface.connect()
+        self.assertEqual([interface.receive() for _ in frames], frames)
+        interface.close()
+        server.join()
+
+
+class RecordTest(unittest.TestCase):
+    def test_record_and_replay(self):
+        path = os.path.join(tempfile.mkdtemp(), "record.xml")
+        frames = [b'<joined roomId="b5a4f8c2"/>', b'<room roomId="b5a4f8c2"><data class="moveRequest"/></room>',
+                  b'<room roomId="b5a4f8c2"><data class="moveRequest"/></room>']
+        server = MockServer([b"<protocol>\n" + b"\n".join(frames)])
+        interface = _NetworkInterface("localhost", server.port, record_file=path)
+        interface.connect()
+        received = [interface.receive() for _ in frames]
+        interface.close()
+        server.join()
+        self.assertEqual(received, frames)
+
+        replay = _ReplayInterface(path)
+        replay.connect()
+        self.assertEqual([replay.receive() for _ in frames], frames)
+        with self.assertRaises(EOFError):
+            replay.receive()
+        replay.close()
@@ -1,9 +1,22 @@
+import heapq
+import json
+import os
+import pickle
 import unittest
 import random
+from unittest.mock import patch
 
 from socha.api.plugin.penguins import *
 
 
+def create_board(fields: List[List[Union[int, str]]]) -> Board:
+    """
+    Creates a board from rows of fish counts or team names, e.g. `[[1, 2], ["ONE", 0]]`.
+    """
+    return Board([[Field(CartesianCoordinate(x, y).to_hex(), field) for x, field in enumerate(row)]
+                  for y, row in enumerate(fields)])
+
+
 def create_random_board() -> Board:
     game_field = []
     teams = ["ONE", "TWO"]
@@ -31,6 +44,25 @@ def create_random_board() -> Board:
     return Board(game_field)
 
 
+movement_fields = [
+    ["ONE", 1, 2, 3, 1, 2, "TWO", 1],
+    [2, 1, 3, 0, 2, 1, 4, 2],
+    [1, "TWO", 2, 1, 3, "ONE", 2, 1],
+    [0, 2, 1, 4, 1, 2, 1, 3],
+    [3, 1, 2, 1, 4, 0, 2, 1],
+    [1, "ONE", 0, 2, 1, 3, "TWO", 2],
+    [2, 1, 3, 1, 2, 1, 0, 4],
+    [1, "TWO", 1, 2, 0, 1, 3, "ONE"],
+]
+
+
+def create_movement_state(turn: int = 8) -> GameState:
+    """
+    Creates a game state after the placement phase, in which both teams have placed all their penguins.
+    """
+    return GameState(board=create_board(movement_fields), turn=turn, start_team=Team("ONE"), fishes=Fishes(4, 4))
+
+
 class VectorTest(unittest.TestCase):
     def testVectorInit(self):
         v = Vector(5, -5)
@@ -78,6 +110,45 @@ class CoordinateTest(unittest.TestCase):
         self.assertEqual(c.to_cartesian().x, HexCoordinate(7, 7).x)
         self.assertEqual(c.to_cartesian().y, HexCoordinate(7, 7).y)
 
+    def test_is_on_board(self):
+        for c in [HexCoordinate(0, 0), HexCoordinate(7, 3), HexCoordinate(8, 4), HexCoordinate(15, 7)]:
+            self.assertTrue(c.is_on_board())
+        for c in [HexCoordinate(-2, 0), HexCoordinate(16, 0), HexCoordinate(0, 8), HexCoordinate(3, -1),
+                  HexCoordinate(2, 1), HexCoordinate(100, 100)]:
+            self.assertFalse(c.is_on_board())
+        self.assertTrue(HexCoordinate(2, 0).is_on_board(width=2, height=1))
+        self.assertFalse(HexCoordinate(4, 0).is_on_board(width=2, height=1))
+
+    def test_index(self):
+        for width in [8, 3]:
+            coordinates = [CartesianCoordinate(x, y).to_hex() for y in range(width) for x in range(width)]
+            self.assertEqual([c.to_index(width) for c in coordinates], list(range(width * width)))
+            self.assertEqual([HexCoordinate.from_index(i, width) for i in range(width * width)], coordinates)
+        self.assertEqual(HexCoordinate(15, 7).to_index(), 63)
+        self.assertEqual(HexCoordinate.from_index(9), HexCoordinate(3, 1))
+        with self.assertRaises(IndexError):
+            HexCoordinate.from_index(-1)
+
+    def test_hex_distance(self):
+        origin = HexCoordinate(0, 0)
+        self.assertEqual(origin.hex_distance(origin), 0)
+        self.assertEqual(origin.hex_distance(HexCoordinate(4, 0)), 2)
+        self.assertEqual(origin.hex_distance(HexCoordinate(3, 3)), 3)
+        self.assertEqual(origin.hex_distance(HexCoordinate(7, 3)), 5)
+        self.assertEqual(HexCoordinate(7, 3).hex_distance(origin), 5)
+        self.assertEqual(HexCoordinate(3, 1).hex_distance(HexCoordinate(1, 5)), 4)
+        for neighbor in HexCoordinate(7, 3).get_neighbors():
+            self.assertEqual(HexCoordinate(7, 3).hex_distance(neighbor), 1)
+
+    def test_direction_to(self):
+        origin = HexCoordinate(7, 3)
+        for direction in Vector().directions:
+            for steps in [1, 3]:
+                target = origin.add_vector(direction.scalar_product(steps))
+                self.assertTrue(origin.direction_to(target).are_identically(direction))
+        for target in [HexCoordinate(7, 3), HexCoordinate(11, 5), HexCoordinate(9, 7), HexCoordinate(8, 3)]:
+            self.assertIsNone(origin.direction_to(target))
+
 
 class MoveTest(unittest.TestCase):
     def testMoveInit(self):
@@ -87,12 +158,84 @@ class MoveTest(unittest.TestCase):
         self.assertEqual(m.to_value.x, 15)
         self.assertEqual(m.to_value.y, 7)
 
+    def test_coordinates(self):
+        slide = Move(from_value=HexCoordinate(1, 1), to_value=HexCoordinate(3, 1))
+        self.assertEqual(slide.from_coordinate(), HexCoordinate(1, 1))
+        self.assertEqual(slide.to_coordinate(), HexCoordinate(3, 1))
+        placement = Move(to_value=HexCoordinate(3, 1))
+        self.assertIsNone(placement.from_coordinate())
+        self.assertEqual(placement.to_coordinate(), HexCoordinate(3, 1))
+
+    def test_equality(self):
+        placement = Move(to_value=HexCoordinate(3, 1))
+        slide = Move(from_value=HexCoordinate(1, 1), to_value=HexCoordinate(3, 1))
+        self.assertEqual(placement, Move(to_value=HexCoordinate(3, 1)))
+        self.assertEqual(slide, Move(from_value=HexCoordinate(1, 1), to_value=HexCoordinate(3, 1)))
+        self.assertNotEqual(placement, slide)
+        self.assertNotEqual(slide, placement)
+        self.assertEqual(len({placement, slide, Move(to_value=HexCoordinate(3, 1))}), 2)
+
+    def test_notation(self):
+        placement = Move(to_value=HexCoordinate(3, 1))
+        slide = Move(from_value=HexCoordinate(3, 1), to_value=HexCoordinate(7, 1))
+        self.assertEqual(placement.to_notation(), "@3,1")
+        self.assertEqual(slide.to_notation(), "3,1->7,1")
+        for move in [placement, slide] + create_movement_state().possible_moves:
+            self.assertEqual(Move.from_notation(move.to_notation()), move)
+        for notation in ["", "@", "@3", "3,1", "3,1->", "a,b->7,1", "@3,1,2"]:
+            with self.assertRaises(ValueError):
+                Move.from_notation(notation)
+
+    def test_sort_key(self):
+        placement = Move(to_value=HexCoordinate(3, 1))
+        slide = Move(from_value=HexCoordinate(1, 1), to_value=HexCoordinate(3, 1))
+        self.assertEqual(placement.sort_key(), (-1, -1, 3, 1))
+        self.assertEqual(slide.sort_key(), (1, 1, 3, 1))
+        self.assertLess(placement, slide)
+        self.assertGreater(slide, placement)
+        self.assertLessEqual(placement, slide)
+        self.assertLessEqual(placement, Move(to_value=HexCoordinate(3, 1)))
+        self.assertGreaterEqual(slide, placement)
+        self.assertFalse(slide <= placement or placement >= slide)
+
+        moves = create_movement_state().possible_moves
+        ordered = sorted(moves)
+        self.assertEqual(ordered, sorted(moves, key=Move.sort_key))
+        shuffled = moves[:]
+        random.Random(3).shuffle(shuffled)
+        self.assertNotEqual(shuffled, ordered)
+        self.assertEqual(sorted(shuffled), ordered)
+
 
 class TeamTest(unittest.TestCase):
     def testTeamInit(self):
         t = Team(color="ONE")
         self.assertEqual(t.color(), "ONE")
 
+    def test_all(self):
+        self.assertEqual([str(team) for team in Team.all()], ["ONE", "TWO"])
+        self.assertEqual([repr(team) for team in Team.all()], ["ONE", "TWO"])
+        self.assertEqual(Team.all(), [Team("ONE"), Team("TWO")])
+        self.assertEqual([team.opponent() for team in Team.all()], [Team("TWO"), Team("ONE")])
+
+    def test_from_index(self):
+        self.assertEqual(Team.from_index(0), Team("ONE"))
+        self.assertEqual(Team.from_index(1), Team("TWO"))
+        self.assertEqual([Team.from_index(index) for index in range(2)], Team.all())
+        for index in [-1, 2]:
+            with self.assertRaises(IndexError):
+                Team.from_index(index)
+
+    def test_opponent_and_equality(self):
+        for team in Team.all():
+            self.assertEqual(team.opponent().opponent(), team)
+            self.assertNotEqual(team.opponent(), team)
+        self.assertNotEqual(Team("ONE"), Team("TWO"))
+        self.assertNotEqual(Team("ONE"), "ONE")
+        self.assertEqual(hash(Team("ONE")), hash(Team("TWO").opponent()))
+        self.assertEqual(len({Team("ONE"), Team("ONE"), Team("TWO")}), 2)
+        self.assertEqual({Team("TWO"): 1}[Team("ONE").opponent()], 1)
+
 
 class FieldTest(unittest.TestCase):
     def testFieldInit(self):
@@ -168,6 +311,319 @@ class BoardTest(unittest.TestCase):
         with self.assertRaises(IndexError):
             self.b.get_field_by_index(random_indices)
 
+    def test_index_and_coordinate(self):
+        for board in [create_board(movement_fields), create_board([[1, 2, 3, 1], [0, 1, 4, 2], [1, 1, 0, 3]])]:
+            size = board.width() * board.height()
+            coordinates = [board.index_to_coordinate(index) for index in range(size)]
+            self.assertEqual(coordinates, [field.coordinate for field in board.get_all_fields()])
+            self.assertEqual([board.coordinate_to_index(coordinate) for coordinate in coordinates], list(range(size)))
+            for index in [-1, size]:
+                with self.assertRaises(IndexError):
+                    board.index_to_coordinate(index)
+            with self.assertRaises(IndexError):
+                board.coordinate_to_index(HexCoordinate(2 * board.width(), 0))
+            with self.assertRaises(IndexError):
+                board.coordinate_to_index(HexCoordinate(1, 0))
+
+    def test_unoccupied_fields_and_occupied_mask(self):
+        board = create_board(movement_fields)
+        unoccupied = board.unoccupied_fields()
+        mask = board.occupied_mask()
+        self.assertEqual(bin(mask).count("1"), 8)
+        for y in range(-1, board.height() + 1):
+            for x in range(-2, 2 * board.width() + 2):
+                coordinate = HexCoordinate(x, y)
+                on_board = board.is_valid(coordinate)
+                occupied = on_board and bool(mask >> board.coordinate_to_index(coordinate) & 1)
+                self.assertEqual([on_board and not occupied, occupied, not on_board].count(True), 1)
+                self.assertEqual(coordinate in unoccupied, on_board and not occupied)
+                if on_board:
+                    self.assertEqual(occupied, board.is_occupied(coordinate))
+        self.assertEqual(len(unoccupied), 64 - 8)
+
+    def test_iter(self):
+        board = create_board(movement_fields)
+        cells = list(board)
+        self.assertEqual(len(cells), board.width() * board.height())
+        self.assertEqual(cells, [(field.coordinate, field.field) for field in board.get_all_fields()])
+        self.assertEqual(cells[0], (HexCoordinate(0, 0), board[HexCoordinate(0, 0)]))
+        self.assertEqual(sum(1 for _, content in create_board([[1, "ONE"], ["TWO", 0]]) if isinstance(content, Team)),
+                         2)
+
+    def test_dimensions(self):
+        self.assertEqual(self.b.width(), 8)
+        self.assertEqual(self.b.height(), 8)
+        self.assertEqual(len(self.b.get_all_fields()), 64)
+
+        board = create_board([[1, 2, 3, 1], [0, 1, 4, 2], [1, 1, 0, 3]])
+        self.assertEqual(board.width(), 4)
+        self.assertEqual(board.height(), 3)
+        self.assertTrue(board.is_valid(HexCoordinate(6, 0)))
+        self.assertTrue(board.is_valid(HexCoordinate(7, 1)))
+        self.assertFalse(board.is_valid(HexCoordinate(8, 0)))
+        self.assertFalse(board.is_valid(HexCoordinate(0, 4)))
+        self.assertEqual([field.coordinate for field in board.get_all_fields()],
+                         [CartesianCoordinate(x, y).to_hex() for y in range(3) for x in range(4)])
+        self.assertEqual(board.get_field_by_index(7).field, 2)
+        with self.assertRaises(IndexError):
+            board.get_field_by_index(12)
+        self.assertEqual([field.coordinate for field in board.get_most_fish()], [HexCoordinate(5, 1)])
+        self.assertEqual([move.to_value for move in board.get_moves_in_direction(HexCoordinate(0, 0), Vector(2, 0))],
+                         [HexCoordinate(2, 0), HexCoordinate(4, 0), HexCoordinate(6, 0)])
+
+    def test_get_item(self):
+        board = create_board([[1, 2], ["ONE", 0]])
+        self.assertEqual(board[HexCoordinate(2, 0)], 2)
+        self.assertEqual(board[HexCoordinate(1, 1)], Team("ONE"))
+        with self.assertRaises(IndexError):
+            board[HexCoordinate(5, 1)]
+
+    def test_simulate_move(self):
+        board = create_board([[1, 2, 3], ["ONE", 1, 0], [2, 1, 1]])
+        snapshot = [[field.field for field in row] for row in board._game_field]
+
+        placed = board.simulate_move(Move(to_value=HexCoordinate(0, 0)), Team("TWO"))
+        self.assertEqual(placed[HexCoordinate(0, 0)], Team("TWO"))
+        self.assertEqual(placed[HexCoordinate(1, 1)], Team("ONE"))
+
+        slid = board.simulate_move(Move(from_value=HexCoordinate(1, 1), to_value=HexCoordinate(3, 1)))
+        self.assertEqual(slid[HexCoordinate(1, 1)], 0)
+        self.assertEqual(slid[HexCoordinate(3, 1)], Team("ONE"))
+
+        self.assertEqual([[field.field for field in row] for row in board._game_field], snapshot)
+        with self.assertRaises(ValueError):
+            board.simulate_move(Move(to_value=HexCoordinate(2, 0)))
+
+    def test_fields_with_fish(self):
+        board = create_board(movement_fields)
+        for value in range(1, 5):
+            expected = [CartesianCoordinate(x, y).to_hex() for y, row in enumerate(movement_fields)
+                        for x, field in enumerate(row) if field == value]
+            self.assertTrue(expected)
+            self.assertEqual(board.fields_with_fish(value), expected)
+            self.assertTrue(all(board[coordinate] == value for coordinate in board.fields_with_fish(value)))
+        for value in [0, 5, -1]:
+            with self.assertRaises(ValueError):
+                board.fields_with_fish(value)
+
+    def test_single_fish_fields(self):
+        board = create_board([[1, 2, 1, "ONE"], [0, 1, 3, 1], ["TWO", 4, 1, 0], [2, 1, 1, 3]])
+        single_fish_fields = [HexCoordinate(0, 0), HexCoordinate(4, 0), HexCoordinate(3, 1), HexCoordinate(7, 1),
+                              HexCoordinate(4, 2), HexCoordinate(3, 3), HexCoordinate(5, 3)]
+        self.assertEqual(board.single_fish_fields(), single_fish_fields)
+
+        state = GameState(board=board, turn=2, start_team=Team("ONE"), fishes=Fishes(1, 1))
+        self.assertEqual([move.to_value for move in state.possible_moves], single_fish_fields)
+        self.assertTrue(all(move.from_value is None for move in state.possible_moves))
+
+    def test_connected_components(self):
+        board = create_board([[1, 2, 3, 4], ["ONE", "TWO", "ONE", "TWO"], [2, 1, 1, 3], [0, 1, 2, "ONE"]])
+        self.assertEqual(board.connected_components(), [
+            [HexCoordinate(0, 0), HexCoordinate(2, 0), HexCoordinate(4, 0), HexCoordinate(6, 0)],
+            [HexCoordinate(0, 2), HexCoordinate(2, 2), HexCoordinate(4, 2), HexCoordinate(6, 2), HexCoordinate(3, 3),
+             HexCoordinate(5, 3)],
+        ])
+
+        board = create_board(movement_fields)
+        components = board.connected_components()
+        self.assertEqual(len(components), 1)
+        self.assertEqual(len(components[0]), sum(len(board.fields_with_fish(value)) for value in range(1, 5)))
+
+    def test_is_field_reachable(self):
+        board = create_board(movement_fields)
+        one = Team("ONE")
+        self.assertTrue(board.is_field_reachable(HexCoordinate(10, 2), HexCoordinate(8, 2), one))
+        self.assertTrue(board.is_field_reachable(HexCoordinate(10, 2), HexCoordinate(6, 2), one))
+        self.assertFalse(board.is_field_reachable(HexCoordinate(10, 2), HexCoordinate(2, 2), one))
+        self.assertFalse(board.is_field_reachable(HexCoordinate(10, 2), HexCoordinate(0, 2), one))
+        self.assertFalse(board.is_field_reachable(HexCoordinate(10, 2), HexCoordinate(9, 5), one))
+        self.assertFalse(board.is_field_reachable(HexCoordinate(10, 2), HexCoordinate(8, 2), Team("TWO")))
+        self.assertFalse(board.is_field_reachable(HexCoordinate(8, 2), HexCoordinate(6, 2), one))
+        self.assertFalse(board.is_field_reachable(HexCoordinate(-2, 2), HexCoordinate(0, 2), one))
+
+    def test_fish_distance_map(self):
+        board = create_board([["ONE", 1, 0, 2], [3, 2, 1, 0], [0, 0, 1, 0], [0, 0, 0, 4]])
+        distances = board.fish_distance_map(HexCoordinate(0, 0))
+        self.assertEqual(sorted(distances, key=lambda entry: (entry[1], entry[0].y, entry[0].x)), [
+            (HexCoordinate(2, 0), 1), (HexCoordinate(1, 1), 1), (HexCoordinate(3, 1), 2), (HexCoordinate(5, 1), 2),
+            (HexCoordinate(4, 2), 2), (HexCoordinate(6, 0), 3),
+        ])
+        self.assertEqual([distance for _, distance in distances], sorted(distance for _, distance in distances))
+        self.assertNotIn(HexCoordinate(7, 3), [position for position, _ in distances])
+
+    def test_region_size(self):
+        board = create_board([["ONE", 1, 0, 2], [3, 2, 1, 0], [0, 0, 1, 0], [0, 0, 0, "TWO"]])
+        self.assertEqual(board.region_size(HexCoordinate(0, 0), Team("ONE")), 6)
+        self.assertEqual(board.region_size(HexCoordinate(7, 3), Team("TWO")), 0)
+        with self.assertRaises(ValueError):
+            board.region_size(HexCoordinate(0, 0), Team("TWO"))
+        with self.assertRaises(ValueError):
+            board.region_size(HexCoordinate(2, 0), Team("ONE"))
+
+    def test_slides_in_direction(self):
+        board = create_board([["ONE", 1, 2, 0, 3], [1, 1, 1, 1, 1], [1, "TWO", 3, 1, 1], [1, 1, 1, 1, 1],
+                              [1, 1, 1, 1, 1]])
+        self.assertEqual(board.slides_in_direction(HexCoordinate(0, 0), Vector(2, 0)),
+                         [HexCoordinate(2, 0), HexCoordinate(4, 0)])
+        self.assertEqual(board.slides_in_direction(HexCoordinate(0, 0), Vector(1, 1)),
+                         [HexCoordinate(1, 1)])
+        self.assertEqual(board.slides_in_direction(HexCoordinate(0, 0), Vector(-2, 0)), [])
+        self.assertEqual([move.to_value for move in board.get_moves_in_direction(HexCoordinate(0, 0), Vector(1, 1))],
+                         board.slides_in_direction(HexCoordinate(0, 0), Vector(1, 1)))
+
+    def test_can_reach(self):
+        board = create_board([[1] * 4 for _ in range(4)])
+        self.assertTrue(all(board.can_reach(HexCoordinate(0, 0), field.coordinate) for field in board.get_all_fields()))
+
+        blocked = create_board([["ONE", 1, 0, 2], [3, 2, 1, 0], [0, 0, 1, 0], [0, 0, 0, 4]])
+        self.assertTrue(blocked.can_reach(HexCoordinate(0, 0), HexCoordinate(6, 0)))
+        self.assertTrue(blocked.can_reach(HexCoordinate(0, 0), HexCoordinate(4, 2)))
+        self.assertTrue(blocked.can_reach(HexCoordinate(0, 0), HexCoordinate(0, 0)))
+        self.assertFalse(blocked.can_reach(HexCoordinate(0, 0), HexCoordinate(7, 3)))
+        self.assertFalse(blocked.can_reach(HexCoordinate(0, 0), HexCoordinate(4, 0)))
+
+    def test_distance_to_nearest_fish(self):
+        board = create_board([["ONE", 1, 0, 2], [3, 2, 1, 0], [0, 0, 1, 0], [0, 0, 0, 4]])
+        origin = HexCoordinate(0, 0)
+        self.assertEqual(board.distance_to_nearest_fish(origin), 1)
+        self.assertEqual(board.distance_to_nearest_fish(origin, 3), 1)
+        self.assertEqual(board.distance_to_nearest_fish(origin, 4), 5)
+        self.assertIsNone(board.distance_to_nearest_fish(origin, 5))
+        self.assertEqual(board.distance_to_nearest_fish(HexCoordinate(7, 3), 4), 0)
+        self.assertEqual(board.distance_to_nearest_fish(HexCoordinate(4, 2), 2), 1)
+
+    def test_edge_and_center_fields(self):
+        board = create_board(movement_fields)
+        for coordinate in [HexCoordinate(0, 0), HexCoordinate(14, 0), HexCoordinate(1, 1), HexCoordinate(15, 3),
+                           HexCoordinate(9, 7)]:
+            self.assertTrue(board.is_edge_field(coordinate))
+        for coordinate in [HexCoordinate(3, 1), HexCoordinate(2, 2), HexCoordinate(13, 5), HexCoordinate(7, 3)]:
+            self.assertFalse(board.is_edge_field(coordinate))
+        with self.assertRaises(IndexError):
+            board.is_edge_field(HexCoordinate(16, 0))
+        self.assertEqual(board.center_fields(),
+                         [HexCoordinate(7, 3), HexCoordinate(9, 3), HexCoordinate(6, 4), HexCoordinate(8, 4)])
+        self.assertEqual(create_board([[1] * 5 for _ in range(5)]).center_fields(), [HexCoordinate(4, 2)])
+
+    def test_place_and_remove_penguin(self):
+        board = create_board(movement_fields)
+        board.place_penguin(HexCoordinate(2, 0), Team("TWO"))
+        self.assertEqual(board[HexCoordinate(2, 0)], Team("TWO"))
+        self.assertIn(HexCoordinate(2, 0), board.get_teams_penguins(Team("TWO")))
+        self.assertEqual(board.penguin_count(Team("TWO")), 5)
+        with self.assertRaises(ValueError):
+            board.place_penguin(HexCoordinate(2, 0), Team("ONE"))
+        with self.assertRaises(IndexError):
+            board.place_penguin(HexCoordinate(16, 0), Team("ONE"))
+
+        board.remove_penguin(HexCoordinate(2, 0))
+        self.assertEqual(board[HexCoordinate(2, 0)], 0)
+        board.remove_penguin(HexCoordinate(0, 0), fish=1)
+        self.assertEqual(board[HexCoordinate(0, 0)], 1)
+        self.assertEqual(board.penguin_count(Team("ONE")), 3)
+        self.assertEqual(board.penguin_count(Team("TWO")), 4)
+        with self.assertRaises(ValueError):
+            board.remove_penguin(HexCoordinate(0, 0))
+
+    def test_stats(self):
+        stats = create_board([[1, 2, "ONE"], [0, 4, 1], ["TWO", 3, 1]]).stats()
+        self.assertEqual(stats.penguins_one, 1)
+        self.assertEqual(stats.penguins_two, 1)
+        self.assertEqual(stats.total_fish, 12)
+        self.assertEqual(stats.fish_fields, {1: 3, 2: 1, 3: 1, 4: 1})
+        self.assertEqual(stats, BoardStats(1, 1, 12, {1: 3, 2: 1, 3: 1, 4: 1}))
+
+        board = create_board(movement_fields)
+        stats = board.stats()
+        self.assertEqual((stats.penguins_one, stats.penguins_two), (4, 4))
+        self.assertEqual(stats.fish_fields, {fish: len(board.fields_with_fish(fish)) for fish in range(1, 5)})
+
+    def test_make_and_unmake_move(self):
+        board = create_board([[1] * 8 for _ in range(8)])
+        original = board._copy()
+        state = GameState(board=original, turn=0, start_team=Team("ONE"), fishes=Fishes(0, 0))
+        rng = random.Random(7)
+        tokens = []
+        while state.possible_moves and len(tokens) < 40:
+            move = rng.choice(state.possible_moves)
+            tokens.append(board.make_move_in_place(move, state.current_team))
+            state = state.perform_move(move)
+            self.assertEqual(board, state.board)
+        self.assertEqual(len(tokens), 40)
+        self.assertNotEqual(board, original)
+
+        for token in reversed(tokens):
+            board.unmake_move(token)
+        self.assertEqual(board, original)
+        self.assertEqual(board.structural_hash(), original.structural_hash())
+        with self.assertRaises(ValueError):
+            board.make_move_in_place(Move(to_value=HexCoordinate(0, 0)))
+
+    def test_penguin_count(self):
+        board = create_board(movement_fields)
+        self.assertEqual(board.penguin_count(Team("ONE")), 4)
+        self.assertEqual(board.penguin_count(Team("TWO")), 4)
+        self.assertEqual(create_board([[1, 1], [1, 1]]).penguin_count(Team("ONE")), 0)
+
+    def test_apply_move(self):
+        board = create_board(movement_fields)
+        move = Move(from_value=HexCoordinate(0, 0), to_value=HexCoordinate(4, 0))
+        new_board = board.apply_move(move)
+        self.assertEqual(new_board, board.simulate_move(move))
+        self.assertEqual(new_board[HexCoordinate(0, 0)], 0)
+        self.assertEqual(new_board[HexCoordinate(4, 0)], Team("ONE"))
+        self.assertEqual(board, create_board(movement_fields))
+        self.assertEqual(len(new_board.compare_to(board)), 2)
+
+    def test_canonical(self):
+        board = create_board(movement_fields)
+        rotated = board.rotated()
+        self.assertNotEqual(board, rotated)
+        self.assertEqual(rotated.rotated(), board)
+        self.assertEqual(rotated[HexCoordinate(15, 7)], board[HexCoordinate(0, 0)])
+        self.assertEqual(board.rotated_coordinate(HexCoordinate(3, 1)), HexCoordinate(12, 6))
+        self.assertEqual(len(rotated.possible_moves_from(HexCoordinate(12, 6))),
+                         len(board.possible_moves_from(HexCoordinate(3, 1))))
+
+        self.assertEqual(board.canonical(), rotated.canonical())
+        self.assertIn(board.canonical(), [board, rotated])
+        self.assertEqual(board.canonical().canonical(), board.canonical())
+        self.assertEqual(board.canonical().to_strings(), min(board.to_strings(), rotated.to_strings()))
+
+        odd = create_board([[1, 2, 3], ["ONE", 2, 1], [2, 1, "TWO"]])
+        mirrored = odd.mirrored()
+        self.assertEqual(mirrored, create_board([[2, 1, "TWO"], ["ONE", 2, 1], [1, 2, 3]]))
+        self.assertEqual(odd.mirrored_coordinate(HexCoordinate(4, 2)), HexCoordinate(4, 0))
+        self.assertEqual(set(mirrored.possible_moves_from(HexCoordinate(1, 1))),
+                         {Move(from_value=HexCoordinate(1, 1), to_value=odd.mirrored_coordinate(move.to_value))
+                          for move in odd.possible_moves_from(HexCoordinate(1, 1))})
+        self.assertEqual(odd.canonical(), mirrored.canonical())
+        self.assertEqual(odd.canonical(), odd)
+
+    def test_structural_hash(self):
+        placed = create_board([[1, 2, 3], ["ONE", 1, 0], [2, 1, 1]])
+        slid = create_board([[1, 2, 3], [1, 1, "ONE"], [2, 1, 1]]).simulate_move(
+            Move(from_value=HexCoordinate(5, 1), to_value=HexCoordinate(1, 1)))
+        slid = slid.simulate_move(Move(to_value=HexCoordinate(5, 1)), Team("TWO"))
+        placed = placed.simulate_move(Move(to_value=HexCoordinate(5, 1)), Team("TWO"))
+        self.assertEqual(placed, slid)
+        self.assertEqual(placed.structural_hash(), slid.structural_hash())
+        self.assertEqual(len({placed, slid}), 1)
+
+        other = placed.simulate_move(Move(to_value=HexCoordinate(0, 0)), Team("ONE"))
+        self.assertNotEqual(placed, other)
+        self.assertNotEqual(placed.structural_hash(), other.structural_hash())
+        self.assertNotEqual(placed, None)
+
+    def test_get_fish_or_zero(self):
+        board = create_board([[3, 0], ["TWO", 1]])
+        self.assertEqual(board.get_fish_or_zero(HexCoordinate(0, 0)), 3)
+        self.assertEqual(board.get_fish_or_zero(HexCoordinate(2, 0)), 0)
+        self.assertEqual(board.get_fish_or_zero(HexCoordinate(1, 1)), 0)
+        self.assertIsNone(board.get_field(HexCoordinate(1, 1)).get_fish())
+        with self.assertRaises(IndexError):
+            board.get_fish_or_zero(HexCoordinate(4, 0))
+
     def test_get_most_fish(self):
         most_fish_fields = self.b.get_most_fish()
         self.assertTrue(isinstance(most_fish_fields, list))
@@ -176,6 +632,18 @@ class BoardTest(unittest.TestCase):
         self.assertTrue(all(field.field >= 0 for field in most_fish_fields))
 
 
+class FishesTest(unittest.TestCase):
+    def test_get_fish_by_team(self):
+        fishes = Fishes(fishes_one=7, fishes_two=3)
+        self.assertEqual(fishes.get_fish_by_team(Team("ONE")), fishes.fishes_one)
+        self.assertEqual(fishes.get_fish_by_team(Team("TWO")), fishes.fishes_two)
+        self.assertEqual(fishes.get_fish_by_team(Team("ONE").opponent()), 3)
+
+    def test_repr(self):
+        self.assertEqual(repr(Fishes(fishes_one=7, fishes_two=3)), "Fishes(ONE=7, TWO=3)")
+        self.assertEqual(str(Fishes(fishes_one=0, fishes_two=12)), "Fishes(ONE=0, TWO=12)")
+
+
 class GameStateTest(unittest.TestCase):
     b = Board(game_field=[[Field(coordinate=CartesianCoordinate(j, i).to_hex(), field=1) for i in range(8)] for j in
                           range(8)])
@@ -190,6 +658,8 @@ class GameStateTest(unittest.TestCase):
 
     def test_perform_low_index_move(self):
         new_state = self.g.perform_move(Move(to_value=HexCoordinate(1, 1)))
+        self.assertEqual(new_state.board[HexCoordinate(1, 1)], Team("TWO"))
+        self.assertEqual(self.g.board[HexCoordinate(1, 1)], 1)
         self.assertEqual(new_state.turn, 2)
         self.assertEqual(new_state.fishes.fishes_one, 1)
         self.assertEqual(new_state.fishes.fishes_two, 1)
@@ -209,3 +679,744 @@ class GameStateTest(unittest.TestCase):
         self.g.possible_moves = self.g._get_possible_moves(self.g.current_team)
         with self.assertRaises(Exception):
             self.g.perform_move(Move(to_value=HexCoordinate(0, 0)))
+
+    def test_turns_remaining(self):
+        board = create_board([[1] * 8 for _ in range(8)])
+        start = GameState(board=board, turn=0, start_team=Team("ONE"), fishes=Fishes(0, 0))
+        self.assertEqual(start.turns_remaining(), 64)
+        self.assertEqual(start.rounds_remaining(), 32)
+
+        near_limit = GameState(board=board, turn=62, start_team=Team("ONE"), fishes=Fishes(0, 0))
+        self.assertEqual(near_limit.turns_remaining(), 2)
+        self.assertEqual(near_limit.rounds_remaining(), 1)
+
+        at_limit = GameState(board=board, turn=64, start_team=Team("ONE"), fishes=Fishes(0, 0))
+        self.assertEqual(at_limit.turns_remaining(), 0)
+        self.assertEqual(at_limit.rounds_remaining(), 0)
+
+    def test_possible_moves_from_penguin(self):
+        state = create_movement_state()
+        self.assertEqual(state.current_team, Team("ONE"))
+        penguin = HexCoordinate(10, 2)
+        moves = state.possible_moves_from_penguin(penguin)
+        self.assertTrue(moves)
+        self.assertTrue(all(move.from_value == penguin for move in moves))
+        self.assertEqual(len(moves), len(state.board.possible_moves_from(penguin)))
+
+        with self.assertRaises(ValueError):
+            state.possible_moves_from_penguin(HexCoordinate(12, 0))
+        with self.assertRaises(ValueError):
+            state.possible_moves_from_penguin(HexCoordinate(2, 0))
+
+    def test_possible_moves_as_tuples(self):
+        state = create_movement_state()
+        tuples = state.possible_moves_as_tuples()
+        self.assertEqual(len(tuples), len(state.possible_moves))
+        self.assertEqual(tuples, [((move.from_value.x, move.from_value.y), (move.to_value.x, move.to_value.y))
+                                  for move in state.possible_moves])
+
+        placement = GameState(board=create_board([[1, 2], [0, 1]]), turn=0, start_team=Team("ONE"),
+                              fishes=Fishes(0, 0))
+        self.assertEqual(placement.possible_moves_as_tuples(), [(None, (0, 0)), (None, (3, 1))])
+
+    def test_legal_destinations(self):
+        state = create_movement_state()
+        for penguin in state.current_pieces:
+            self.assertEqual(state.legal_destinations(penguin),
+                             [move.to_value for move in state.possible_moves_from_penguin(penguin)])
+        self.assertTrue(state.legal_destinations(HexCoordinate(10, 2)))
+        with self.assertRaises(ValueError):
+            state.legal_destinations(HexCoordinate(12, 0))
+
+    def test_repr(self):
+        state = GameState(board=create_board(movement_fields), turn=9, start_team=Team("ONE"), fishes=Fishes(7, 3),
+                          last_move=Move(from_value=HexCoordinate(0, 0), to_value=HexCoordinate(2, 0)))
+        self.assertEqual(repr(state), "GameState(turn=9, round=5, current_team=TWO, fishes_one=7, fishes_two=3, "
+                                      "last_move=Move(from = HexCoordinate(0, 0), to = HexCoordinate(2, 0)))")
+
+    def test_fish_advantage(self):
+        state = GameState(board=create_board(movement_fields), turn=8, start_team=Team("ONE"), fishes=Fishes(12, 7))
+        self.assertEqual(state.fish_advantage(Team("ONE")), 5)
+        self.assertEqual(state.fish_advantage(Team("TWO")), -5)
+        self.assertEqual(create_movement_state().fish_advantage(Team("ONE")), 0)
+
+    def test_in_placement_phase(self):
+        fields = [row[:] for row in movement_fields]
+        fields[7][7] = 1
+        state = GameState(board=create_board(fields), turn=7, start_team=Team("TWO"), fishes=Fishes(3, 4))
+        self.assertEqual(state.current_team, Team("ONE"))
+        self.assertEqual(state.board.penguin_count(Team("ONE")), 3)
+        self.assertTrue(state.in_placement_phase())
+        self.assertTrue(all(move.from_value is None for move in state.possible_moves))
+
+        state = state.perform_move(Move(to_value=HexCoordinate(15, 7)))
+        self.assertEqual(state.board.penguin_count(Team("ONE")), 4)
+        self.assertFalse(state.in_placement_phase())
+        self.assertTrue(all(move.from_value is not None for move in state.possible_moves))
+        self.assertFalse(create_movement_state().in_placement_phase())
+
+    def test_phase(self):
+        state = GameState(board=create_board([[1] * 8 for _ in range(8)]), turn=0, start_team=Team("ONE"),
+                          fishes=Fishes(0, 0))
+        self.assertEqual(state.phase(), GamePhase.PLACEMENT)
+        self.assertEqual(create_movement_state().phase(), GamePhase.MOVEMENT)
+
+        stuck = GameState(board=create_board([["ONE", 0, 0], [0, 2, 0], [0, 0, "TWO"]]), turn=2,
+                          start_team=Team("ONE"), fishes=Fishes(1, 1))
+        self.assertTrue(stuck.in_placement_phase())
+        self.assertEqual(stuck.phase(), GamePhase.FINISHED)
+
+    def test_placements_remaining(self):
+        state = GameState(board=create_board([[1] * 8 for _ in range(8)]), turn=0, start_team=Team("ONE"),
+                          fishes=Fishes(0, 0))
+        counts = []
+        for _ in range(8):
+            counts.append([(state.penguin_count(team), state.placements_remaining(team))
+                           for team in [Team("ONE"), Team("TWO")]])
+            state = state.perform_move(state.possible_moves[0])
+        self.assertEqual(counts, [[(0, 4), (0, 4)], [(1, 3), (0, 4)], [(1, 3), (1, 3)], [(2, 2), (1, 3)],
+                                  [(2, 2), (2, 2)], [(3, 1), (2, 2)], [(3, 1), (3, 1)], [(4, 0), (3, 1)]])
+        self.assertEqual(state.penguin_count(Team("TWO")), 4)
+        self.assertEqual(state.placements_remaining(Team("TWO")), 0)
+        self.assertFalse(state.in_placement_phase())
+
+    def test_is_draw(self):
+        board = create_board([["ONE", "TWO"], [0, 0]])
+        tied = GameState(board=board, turn=2, start_team=Team("ONE"), fishes=Fishes(5, 5))
+        self.assertTrue(tied.is_over())
+        self.assertTrue(tied.is_draw())
+        self.assertIsNone(tied.winner())
+
+        won = GameState(board=board, turn=2, start_team=Team("ONE"), fishes=Fishes(5, 6))
+        self.assertFalse(won.is_draw())
+        self.assertEqual(won.winner(), Team("TWO"))
+
+        unfinished = create_movement_state()
+        self.assertFalse(unfinished.is_over())
+        self.assertFalse(unfinished.is_draw())
+        self.assertIsNone(unfinished.winner())
+
+    def test_validate(self):
+        state = create_movement_state()
+        self.assertIsNone(state.validate())
+        self.assertIsNone(state.perform_move(state.possible_moves[0]).validate())
+
+        fields = [row[:] for row in movement_fields]
+        fields[0][1] = "ONE"
+        with self.assertRaisesRegex(ValueError, "Team ONE has 5 penguins"):
+            GameState(board=create_board(fields), turn=9, start_team=Team("ONE"), fishes=Fishes(5, 4)).validate()
+        with self.assertRaisesRegex(ValueError, "Team TWO has collected 3 fish"):
+            GameState(board=state.board, turn=8, start_team=Team("ONE"), fishes=Fishes(4, 3)).validate()
+        with self.assertRaisesRegex(ValueError, "8 penguins have been placed in only 7 turns"):
+            GameState(board=state.board, turn=7, start_team=Team("ONE"), fishes=Fishes(4, 4)).validate()
+        with self.assertRaisesRegex(ValueError, "33 fish have been collected in 8 turns"):
+            GameState(board=state.board, turn=8, start_team=Team("ONE"), fishes=Fishes(29, 4)).validate()
+        with self.assertRaisesRegex(ValueError, "The turn -1 is negative"):
+            GameState(board=create_board([[1] * 8 for _ in range(8)]), turn=-1, start_team=Team("ONE"),
+                      fishes=Fishes(0, 0)).validate()
+        broken_round = create_movement_state()
+        broken_round.round = 3
+        with self.assertRaisesRegex(ValueError, "The round 3 doesn't match the turn 8"):
+            broken_round.validate()
+
+    def test_validate_board(self):
+        board = create_board(movement_fields)
+        board._game_field[0][1] = Field(HexCoordinate(0, 0), 1)
+        with self.assertRaisesRegex(ValueError, r"The field on HexCoordinate\(2, 0\) has the coordinate"):
+            GameState(board=board, turn=8, start_team=Team("ONE"), fishes=Fishes(4, 4)).validate()
+
+        board = create_board(movement_fields)
+        board._game_field[3][0] = Field(HexCoordinate(1, 3), 5)
+        with self.assertRaisesRegex(ValueError, "holds 5, but only 0 to 4 fish or a penguin are allowed"):
+            GameState(board=board, turn=8, start_team=Team("ONE"), fishes=Fishes(4, 4)).validate()
+
+        state = create_movement_state()
+        state.board._game_field[7].pop()
+        with self.assertRaisesRegex(ValueError, "Row 7 has 7 fields, but row 0 has 8"):
+            state.validate()
+
+        state = create_movement_state()
+        with patch.object(state.board.get_field(HexCoordinate(10, 2)), "get_fish", return_value=2):
+            with self.assertRaisesRegex(ValueError, r"The field on HexCoordinate\(10, 2\) holds a penguin and 2 fish"):
+                state.validate()
+
+        full = GameState(board=create_board([[4, 4, "ONE", 4], [4, 4, 4, "TWO"]]), turn=3, start_team=Team("ONE"),
+                         fishes=Fishes(7, 2))
+        with self.assertRaisesRegex(ValueError, "9 fish have been collected and 24 are on the board, "
+                                                "but the 8 fields can only hold 32"):
+            full.validate()
+        self.assertIsNone(full.clone_with_board(create_board([[4, 4, "ONE", 4], [4, 0, 0, "TWO"]])).validate())
+
+    def test_canonical(self):
+        state = create_movement_state().perform_move(Move(from_value=HexCoordinate(10, 2),
+                                                          to_value=HexCoordinate(8, 2)))
+        rotated = state.clone_with_board(state.board.rotated())
+        rotated.last_move = Move(from_value=HexCoordinate(5, 5), to_value=HexCoordinate(7, 5))
+        self.assertEqual(state.canonical(), rotated.canonical())
+        self.assertEqual(state.canonical().current_team, state.current_team)
+        self.assertEqual(state.canonical().fishes, state.fishes)
+        self.assertEqual(len(state.canonical().possible_moves), len(state.possible_moves))
+
+    def test_clone_with_board(self):
+        move = Move(from_value=HexCoordinate(10, 2), to_value=HexCoordinate(8, 2))
+        state = create_movement_state().perform_move(move)
+        board = create_board(movement_fields)
+        board.remove_penguin(HexCoordinate(12, 0), fish=1)
+        clone = state.clone_with_board(board)
+        self.assertIs(clone.board, board)
+        self.assertEqual((clone.turn, clone.round, clone.start_team, clone.fishes, clone.last_move),
+                         (state.turn, state.round, state.start_team, state.fishes, state.last_move))
+        self.assertEqual(clone.current_team, Team("TWO"))
+        self.assertTrue(all(move.from_value is None for move in clone.possible_moves))
+        self.assertNotEqual(state.board, board)
+
+    def test_make_move(self):
+        placement = GameState(board=create_board([[1, 2, 1], ["ONE", 1, 3], [0, 1, "TWO"]]), turn=2,
+                              start_team=Team("ONE"), fishes=Fishes(1, 1))
+        self.assertEqual(placement.make_move(None, HexCoordinate(4, 0)), Move(to_value=HexCoordinate(4, 0)))
+        with self.assertRaisesRegex(ValueError, "only be placed on unoccupied fields with one fish"):
+            placement.make_move(None, HexCoordinate(2, 0))
+        with self.assertRaisesRegex(ValueError, "not all penguins have been placed"):
+            placement.make_move(HexCoordinate(1, 1), HexCoordinate(0, 0))
+
+        state = create_movement_state()
+        slide = state.make_move(HexCoordinate(10, 2), HexCoordinate(8, 2))
+        self.assertEqual(slide, Move(from_value=HexCoordinate(10, 2), to_value=HexCoordinate(8, 2)))
+        self.assertTrue(state.is_valid_move(slide))
+        with self.assertRaisesRegex(ValueError, "can't be reached"):
+            state.make_move(HexCoordinate(10, 2), HexCoordinate(0, 2))
+        with self.assertRaisesRegex(ValueError, "no penguin of team ONE"):
+            state.make_move(HexCoordinate(12, 0), HexCoordinate(10, 0))
+        with self.assertRaisesRegex(ValueError, "not on the board"):
+            state.make_move(HexCoordinate(10, 2), HexCoordinate(16, 2))
+        with self.assertRaisesRegex(ValueError, "have been placed already"):
+            state.make_move(None, HexCoordinate(2, 0))
+
+    def test_perform_null_move(self):
+        board = create_board([["ONE", "ONE", 0, 1], ["ONE", "ONE", 0, 2], [0, 0, 0, "TWO"], ["TWO", "TWO", "TWO", 1]])
+        blocked = GameState(board=board, turn=8, start_team=Team("ONE"), fishes=Fishes(4, 4))
+        self.assertEqual(blocked.current_team, Team("TWO"))
+        self.assertEqual(blocked._team_by_turn(), Team("ONE"))
+        passed = blocked.perform_null_move()
+        self.assertEqual(passed.turn, 9)
+        self.assertEqual(passed._team_by_turn(), Team("TWO"))
+        self.assertEqual(passed.current_team, Team("TWO"))
+        self.assertEqual(passed.board, blocked.board)
+        self.assertIsNot(passed.board, blocked.board)
+        self.assertEqual(passed.fishes, blocked.fishes)
+        self.assertEqual(passed.possible_moves, blocked.possible_moves)
+        self.assertEqual(passed.undo(), blocked)
+        self.assertIsNot(passed.undo().board, passed.board)
+        with self.assertRaises(ValueError):
+            passed.perform_null_move()
+
+        moved = passed.perform_move(passed.possible_moves[0])
+        moved.zobrist_hash()
+        passed_again = moved.perform_null_move()
+        self.assertEqual(passed_again.last_move_fish_gain(), moved.last_move_fish_gain())
+        self.assertEqual(passed_again.zobrist_hash(), GameState(board=passed_again.board, turn=passed_again.turn,
+                                                                start_team=Team("ONE"),
+                                                                fishes=passed_again.fishes).zobrist_hash())
+        self.assertEqual(passed_again.undo(), moved)
+        self.assertEqual(passed_again.undo().undo(), passed)
+        self.assertEqual(passed_again.undo().undo().undo(), blocked)
+        with self.assertRaises(ValueError):
+            create_movement_state().perform_null_move()
+        with self.assertRaises(ValueError):
+            GameState(board=create_board([["ONE", "TWO"], [0, 0]]), turn=2, start_team=Team("ONE"),
+                      fishes=Fishes(1, 1)).perform_null_move()
+
+    def test_current_team_when_both_are_blocked(self):
+        board = create_board([["ONE", "ONE", "TWO", "TWO"], [0, 0, 0, 0], ["ONE", "ONE", "TWO", "TWO"], [0, 0, 0, 0]])
+        for turn, team in [(16, Team("ONE")), (17, Team("TWO"))]:
+            state = GameState(board=board, turn=turn, start_team=Team("ONE"), fishes=Fishes(10, 10))
+            self.assertTrue(state.is_over())
+            self.assertEqual(state.current_team, team)
+            self.assertEqual(state.other_team, team.opponent())
+            self.assertEqual(state.possible_moves, [])
+            self.assertEqual(state.current_pieces, board.get_teams_penguins(team))
+            self.assertIsNone(state.perform_random_move())
+
+    def test_best_greedy_move(self):
+        state = create_movement_state()
+        move = state.best_greedy_move()
+        self.assertEqual(move, state.most_valuable_move())
+        self.assertTrue(all(state.board.get_fish_or_zero(possible_move.to_value)
+                            <= state.board.get_fish_or_zero(move.to_value) for possible_move in state.possible_moves))
+        self.assertIsNone(GameState(board=create_board([["ONE", 0], [0, "TWO"]]), turn=8, start_team=Team("ONE"),
+                                    fishes=Fishes(4, 4)).best_greedy_move())
+
+    def test_perform_move_index(self):
+        state = create_movement_state()
+        for index in [0, 5, len(state.possible_moves) - 1]:
+            self.assertEqual(state.perform_move_index(index), state.perform_move(state.possible_moves[index]))
+        self.assertEqual(state.perform_move_index(2, Team("ONE")), state.perform_move(state.possible_moves[2]))
+        for index in [-1, len(state.possible_moves)]:
+            with self.assertRaises(IndexError):
+                state.perform_move_index(index)
+        with self.assertRaises(ValueError):
+            state.perform_move_index(0, Team("TWO"))
+
+    def test_book_move(self):
+        state = GameState(board=create_board([[1] * 8 for _ in range(8)]), turn=0, start_team=Team("ONE"),
+                          fishes=Fishes(0, 0))
+        book = OpeningBook()
+        book.add(state, Move(to_value=HexCoordinate(7, 3)))
+        self.assertEqual(len(book), 1)
+        self.assertEqual(state.book_move(book), Move(to_value=HexCoordinate(7, 3)))
+        self.assertEqual(state.clone_with_board(state.board).book_move(book), Move(to_value=HexCoordinate(7, 3)))
+        self.assertIsNone(state.perform_move(Move(to_value=HexCoordinate(0, 0))).book_move(book))
+        self.assertIsNone(state.book_move(OpeningBook()))
+        with self.assertRaises(ValueError):
+            book.add(state, Move(from_value=HexCoordinate(0, 0), to_value=HexCoordinate(2, 0)))
+
+    def test_perform_move_when_over(self):
+        state = GameState(board=create_board([["ONE", 0], [0, "TWO"]]), turn=8, start_team=Team("ONE"),
+                          fishes=Fishes(4, 4))
+        self.assertTrue(state.is_over())
+        with self.assertRaisesRegex(GameOverError, "finished game"):
+            state.perform_move(Move(from_value=HexCoordinate(0, 0), to_value=HexCoordinate(2, 0)))
+
+    def test_fish_gained_by(self):
+        for state in [create_movement_state(), GameState(board=create_random_board(), turn=0,
+                                                          start_team=Team("ONE"), fishes=Fishes(0, 0))]:
+            team = state.current_team
+            for move in state.possible_moves:
+                after = state.perform_move(move)
+                self.assertEqual(state.fish_gained_by(move),
+                                 after.fishes.get_fish_by_team(team) - state.fishes.get_fish_by_team(team))
+
+    def test_most_valuable_move(self):
+        state = create_movement_state()
+        move = state.most_valuable_move()
+        fish = [state.board.get_fish_or_zero(possible_move.to_value) for possible_move in state.possible_moves]
+        self.assertEqual(state.board.get_fish_or_zero(move.to_value), max(fish))
+        self.assertEqual(move, state.possible_moves[fish.index(max(fish))])
+        self.assertEqual(state.most_valuable_move(), move)
+
+        stuck = GameState(board=create_board([["ONE", 0], [0, "TWO"]]), turn=8, start_team=Team("ONE"),
+                          fishes=Fishes(4, 4))
+        self.assertIsNone(stuck.most_valuable_move())
+
+    def test_perform_random_move(self):
+        state = create_movement_state()
+        random_state = state.perform_random_move(seed=42)
+        self.assertIn(random_state.last_move, state.possible_moves)
+        self.assertEqual(random_state.turn, 9)
+        self.assertEqual(state.perform_random_move(seed=42), random_state)
+        self.assertGreater(len({state.perform_random_move(seed=seed).last_move for seed in range(20)}), 1)
+        self.assertIsNotNone(state.perform_random_move())
+
+        finished = GameState(board=create_board([["ONE", "TWO"], [0, 0]]), turn=2, start_team=Team("ONE"),
+                             fishes=Fishes(5, 5))
+        self.assertIsNone(finished.perform_random_move(seed=42))
+
+    def test_turns_remaining_estimate(self):
+        state = GameState(board=create_board([[1] * 8 for _ in range(8)]), turn=0, start_team=Team("ONE"),
+                          fishes=Fishes(0, 0))
+        self.assertEqual(state.turns_remaining_estimate(), 64)
+        rng = random.Random(11)
+        while not state.is_over():
+            estimate = state.turns_remaining_estimate()
+            self.assertLessEqual(estimate, state.turns_remaining())
+            state = state.perform_move(rng.choice(state.possible_moves))
+            self.assertLess(state.turns_remaining_estimate(), estimate)
+        self.assertEqual(state.turns_remaining_estimate(), 0)
+
+        state = create_movement_state()
+        self.assertLess(state.turns_remaining_estimate(), state.turns_remaining())
+
+    def test_simulate_random_game(self):
+        state = create_movement_state()
+        end = state.simulate_random_game(seed=5)
+        self.assertTrue(end.is_over())
+        self.assertGreater(end.turn, state.turn)
+        self.assertEqual(end, state.simulate_random_game(seed=5))
+        self.assertEqual(end.winner(), state.random_playout(seed=5))
+        self.assertEqual(state, create_movement_state())
+
+    def test_random_playout(self):
+        board = create_board([["ONE", "ONE", "ONE", "ONE"], [0, 0, 0, 0], ["TWO", "TWO", "TWO", "TWO"], [0, 0, 0, 3]])
+        state = GameState(board=board, turn=8, start_team=Team("ONE"), fishes=Fishes(5, 4))
+        self.assertEqual(state.current_team, Team("TWO"))
+        self.assertEqual([state.random_playout(seed) for seed in range(5)], [Team("TWO")] * 5)
+        self.assertEqual(state.perform_move(state.possible_moves[0]).random_playout(), Team("TWO"))
+        self.assertIsNone(GameState(board=board, turn=8, start_team=Team("ONE"), fishes=Fishes(7, 4)).random_playout())
+
+        state = create_movement_state()
+        self.assertEqual(state.random_playout(seed=42), state.random_playout(seed=42))
+
+    def test_evaluate(self):
+        state = create_movement_state()
+        one_moves = len(state.possible_moves)
+        two_moves = len(state.opponent_possible_moves())
+        self.assertAlmostEqual(state.evaluate(Team("ONE")), 0.1 * (one_moves - two_moves))
+        self.assertAlmostEqual(state.evaluate(Team("TWO")), -state.evaluate(Team("ONE")))
+
+        ahead = GameState(board=state.board, turn=8, start_team=Team("ONE"), fishes=Fishes(9, 4))
+        self.assertGreater(ahead.evaluate(Team("ONE")), state.evaluate(Team("ONE")))
+        self.assertLess(ahead.evaluate(Team("TWO")), state.evaluate(Team("TWO")))
+
+        fields = [row[:] for row in movement_fields]
+        fields[2][4] = 0
+        fields[2][3] = 0
+        cornered = GameState(board=create_board(fields), turn=8, start_team=Team("ONE"), fishes=Fishes(4, 4))
+        self.assertGreater(state.evaluate(Team("ONE")), cornered.evaluate(Team("ONE")))
+
+        class FishOnly(GameState):
+            mobility_weight = 0.0
+
+        fish_only = FishOnly(board=state.board, turn=8, start_team=Team("ONE"), fishes=Fishes(9, 4))
+        self.assertEqual(fish_only.evaluate(Team("ONE")), 5.0)
+        self.assertEqual(fish_only.fishes, Fishes(9, 4))
+
+    def test_advantage_sign(self):
+        state = create_movement_state()
+        winning = GameState(board=state.board, turn=8, start_team=Team("ONE"), fishes=Fishes(12, 4))
+        self.assertEqual(winning.advantage_sign(Team("ONE")), 1)
+        self.assertEqual(winning.advantage_sign(Team("TWO")), -1)
+
+        losing = GameState(board=state.board, turn=8, start_team=Team("ONE"), fishes=Fishes(4, 5))
+        self.assertEqual(losing.advantage_sign(Team("ONE")), -1)
+        self.assertEqual(losing.advantage_sign(Team("TWO")), 1)
+
+        self.assertEqual(state.fishes, Fishes(4, 4))
+        self.assertLess(len(state.possible_moves), len(state.opponent_possible_moves()))
+        self.assertEqual(state.advantage_sign(Team("ONE")), -1)
+        self.assertEqual(state.advantage_sign(Team("TWO")), 1)
+
+        even = create_board([[1] * 8 for _ in range(8)])
+        even_state = GameState(board=even, turn=0, start_team=Team("ONE"), fishes=Fishes(0, 0))
+        self.assertEqual(even_state.advantage_sign(Team("ONE")), 0)
+        self.assertEqual(even_state.advantage_sign(Team("TWO")), 0)
+
+    def test_mobility(self):
+        board = create_board([["ONE", 1, 1, 1, 3], [0, 0, 0, "TWO", 0], ["ONE", "ONE", "ONE", "TWO", "TWO"],
+                              ["TWO", 0, 0, 0, 0]])
+        state = GameState(board=board, turn=8, start_team=Team("TWO"), fishes=Fishes(4, 4))
+        self.assertEqual(sorted(state.mobility(Team("ONE")), key=lambda entry: (entry[0].y, entry[0].x)), [
+            (HexCoordinate(0, 0), 4), (HexCoordinate(0, 2), 0), (HexCoordinate(2, 2), 0), (HexCoordinate(4, 2), 0),
+        ])
+        self.assertEqual(sorted(state.mobility(Team("TWO")), key=lambda entry: (entry[0].y, entry[0].x)), [
+            (HexCoordinate(7, 1), 2), (HexCoordinate(6, 2), 0), (HexCoordinate(8, 2), 0), (HexCoordinate(1, 3), 0),
+        ])
+        self.assertEqual(sum(count for _, count in state.mobility(Team("TWO"))), len(state.possible_moves))
+
+    def test_threats_against(self):
+        board = create_board([["ONE", 1, 1, 1, 3], [0, 0, 0, "TWO", 0], ["ONE", "ONE", "ONE", "TWO", "TWO"],
+                              ["TWO", 0, 0, 0, 0]])
+        state = GameState(board=board, turn=8, start_team=Team("TWO"), fishes=Fishes(4, 4))
+        self.assertEqual({move.to_value for move in state.possible_moves}, {HexCoordinate(6, 0), HexCoordinate(8, 0)})
+        self.assertEqual(state.threats_against(Team("ONE")), [HexCoordinate(6, 0)])
+        self.assertEqual(state.threats_against(Team("TWO")), [HexCoordinate(4, 0), HexCoordinate(6, 0)])
+
+    def test_contested_fields(self):
+        board = create_board([["ONE", 1, 1, 0, 0], [0, 3, 0, 1, 0], [1, 1, 4, 1, 1], [0, 1, 0, 3, 0],
+                              [0, 0, 1, 1, "TWO"]])
+        state = GameState(board=board, turn=2, start_team=Team("ONE"), fishes=Fishes(1, 1))
+        self.assertEqual(state.contested_fields(), [HexCoordinate(2, 2), HexCoordinate(4, 2)])
+        self.assertEqual(create_movement_state().contested_fields(), create_movement_state(turn=9).contested_fields())
+
+    def test_pickle(self):
+        move = Move(from_value=HexCoordinate(10, 2), to_value=HexCoordinate(8, 2))
+        state = create_movement_state().perform_move(move)
+        copy = pickle.loads(pickle.dumps(state))
+        self.assertEqual(copy, state)
+        self.assertEqual(hash(copy), hash(state))
+        self.assertEqual(copy.possible_moves, state.possible_moves)
+        self.assertEqual(pickle.loads(pickle.dumps(state.board)), state.board)
+        self.assertEqual(pickle.loads(pickle.dumps(state.last_move)), state.last_move)
+        self.assertEqual(pickle.loads(pickle.dumps(Team("TWO"))), Team("TWO"))
+        self.assertNotEqual(copy, create_movement_state())
+
+    def test_possible_moves_without_duplicates(self):
+        # the rays of these penguins cross each other, but the moves still differ in the penguin they start from
+        fields = [[3] * 8 for _ in range(8)]
+        for x, y in [(0, 0), (7, 0), (0, 7), (7, 7)]:
+            fields[y][x] = "ONE"
+        for x, y in [(3, 3), (4, 4), (3, 4), (4, 3)]:
+            fields[y][x] = "TWO"
+        for turn in [8, 9]:
+            state = GameState(board=create_board(fields), turn=turn, start_team=Team("ONE"), fishes=Fishes(0, 0))
+            self.assertTrue(state.possible_moves)
+            self.assertEqual(len(state.possible_moves), len(set(state.possible_moves)))
+
+        placement = GameState(board=create_board([[1] * 8 for _ in range(8)]), turn=0, start_team=Team("ONE"),
+                              fishes=Fishes(0, 0))
+        self.assertEqual(len(placement.possible_moves), 64)
+        self.assertEqual(len(set(placement.possible_moves)), 64)
+        self.assertFalse(placement.is_valid_move(Move(from_value=HexCoordinate(2, 0), to_value=HexCoordinate(0, 0))))
+
+    def test_is_valid_move(self):
+        coordinates = [CartesianCoordinate(x, y).to_hex() for y in range(8) for x in range(8)]
+        coordinates += [HexCoordinate(16, 0), HexCoordinate(1, 0), HexCoordinate(-2, 2)]
+        fields = [row[:] for row in movement_fields]
+        fields[7][7] = 1
+        placement = GameState(board=create_board(fields), turn=7, start_team=Team("TWO"), fishes=Fishes(3, 4))
+        self.assertTrue(placement.in_placement_phase())
+        for state in [create_movement_state(), create_movement_state(turn=9), placement]:
+            for from_value in coordinates + [None]:
+                for to_value in coordinates:
+                    move = Move(from_value=from_value, to_value=to_value)
+                    self.assertEqual(state.is_valid_move(move), move in state.possible_moves, move)
+        self.assertFalse(placement.is_valid_move(None))
+
+    def test_opponent_possible_moves(self):
+        state = create_movement_state()
+        self.assertEqual(state.current_team, Team("ONE"))
+        opponent_moves = state.opponent_possible_moves()
+        self.assertTrue(opponent_moves)
+        self.assertEqual(opponent_moves, state._get_possible_moves(Team("TWO")))
+        self.assertEqual(opponent_moves, create_movement_state(turn=9).possible_moves)
+        self.assertTrue(all(state.board[move.from_value] == Team("TWO") for move in opponent_moves))
+
+    def test_opponent_possible_moves_in_placement_phase(self):
+        board = create_board([[1, 2, 1, 3], ["ONE", 1, 0, 1], [2, "TWO", 1, 4], [1, 3, 0, 1]])
+        state = GameState(board=board, turn=2, start_team=Team("ONE"), fishes=Fishes(1, 1))
+        self.assertTrue(state.in_placement_phase())
+        self.assertEqual(state.opponent_possible_moves(), state._get_possible_moves(state.current_team.opponent()))
+        self.assertEqual([move.to_value for move in state.opponent_possible_moves()], board.single_fish_fields())
+
+        fields = [row[:] for row in movement_fields]
+        fields[7][7] = 1
+        state = GameState(board=create_board(fields), turn=7, start_team=Team("TWO"), fishes=Fishes(3, 4))
+        self.assertEqual(state.current_team, Team("ONE"))
+        self.assertTrue(all(move.from_value is None for move in state.possible_moves))
+        self.assertEqual(state.opponent_possible_moves(), state._get_possible_moves(Team("TWO")))
+        self.assertTrue(state.opponent_possible_moves())
+        self.assertTrue(all(state.board[move.from_value] == Team("TWO") for move in state.opponent_possible_moves()))
+
+    def test_iter_moves(self):
+        state = create_movement_state()
+        moves = state.iter_moves()
+        self.assertIs(iter(moves), moves)
+        checked = []
+        is_destination_valid = state.board._is_destination_valid
+        with patch.object(state.board, "_is_destination_valid",
+                          side_effect=lambda field: checked.append(field) or is_destination_valid(field)):
+            first = next(moves)
+            first_checked = list(checked)
+            rest = list(moves)
+        self.assertEqual(first, state.possible_moves[0])
+        self.assertEqual(moves.generated, len(state.possible_moves))
+        self.assertEqual(rest, state.possible_moves[1:])
+        # the first move only needs the first steps of the first penguin, not the rays of the other penguins
+        self.assertTrue(all(first.from_value.direction_to(field) for field in first_checked))
+        self.assertLess(len(first_checked), len(checked))
+        self.assertTrue(any(first.from_value.direction_to(field) is None for field in checked))
+        with self.assertRaises(StopIteration):
+            next(moves)
+
+        self.assertEqual(list(state.iter_moves(Team("TWO"))), state.opponent_possible_moves())
+        placement = GameState(board=create_board([[1, 2, 1], ["ONE", 1, 3], [0, 1, "TWO"]]), turn=2,
+                              start_team=Team("ONE"), fishes=Fishes(1, 1))
+        self.assertEqual(list(placement.iter_moves()), placement.possible_moves)
+
+    def test_first_turn_and_half_of_round(self):
+        board = create_board([[1] * 8 for _ in range(8)])
+        states = [GameState(board=board, turn=turn, start_team=Team("ONE"), fishes=Fishes(0, 0)) for turn in range(4)]
+        self.assertEqual([state.is_first_turn() for state in states], [True, False, False, False])
+        self.assertEqual([state.round for state in states], [0, 1, 1, 2])
+        self.assertEqual([state.half_of_round() for state in states], [1, 0, 1, 0])
+
+    def test_is_my_turn(self):
+        board = create_board([[1] * 8 for _ in range(8)])
+        first = GameState(board=board, turn=0, start_team=Team("TWO"), fishes=Fishes(0, 0))
+        second = first.perform_move(first.possible_moves[0])
+        self.assertTrue(first.is_my_turn(Team("TWO")))
+        self.assertFalse(first.is_my_turn(Team("ONE")))
+        self.assertTrue(second.is_my_turn(Team("ONE")))
+        self.assertFalse(second.is_my_turn(Team("TWO")))
+
+    def test_is_first_move(self):
+        board = create_board([[1] * 8 for _ in range(8)])
+        first = GameState(board=board, turn=0, start_team=Team("TWO"), fishes=Fishes(0, 0))
+        self.assertTrue(first.is_first_move())
+        self.assertTrue(first.is_my_turn(first.start_team))
+        second = first.perform_move(first.possible_moves[0])
+        self.assertFalse(second.is_first_move())
+        self.assertTrue(second.undo().is_first_move())
+        self.assertFalse(create_movement_state().is_first_move())
+
+    def test_history(self):
+        state = create_movement_state()
+        self.assertIsNone(state.history)
+        self.assertIsNone(state.perform_move(state.possible_moves[0]).history)
+
+        tracked = state.with_history()
+        self.assertEqual(tracked, state)
+        self.assertEqual(tracked.history, [])
+        moves = []
+        rng = random.Random(2)
+        for _ in range(5):
+            moves.append(rng.choice(tracked.possible_moves))
+            tracked = tracked.perform_move(moves[-1])
+        self.assertEqual(tracked.history, moves)
+        self.assertEqual(tracked.undo().history, moves[:-1])
+        self.assertEqual(tracked.perform_move(tracked.possible_moves[0]).history, moves + [tracked.possible_moves[0]])
+        self.assertEqual(tracked.history, moves)
+        for _ in range(5):
+            tracked = tracked.undo()
+        self.assertEqual(tracked.history, [])
+        self.assertEqual(state.perform_move(moves[0]).with_history().perform_move(moves[1]).undo().undo().history, [])
+
+        board = create_board([["ONE", "ONE", 0, 1], ["ONE", "ONE", 0, 2], [0, 0, 0, "TWO"], ["TWO", "TWO", "TWO", 1]])
+        passed = GameState(board=board, turn=8, start_team=Team("ONE"), fishes=Fishes(4, 4)).with_history() \
+            .perform_null_move()
+        self.assertEqual(passed.history, [])
+        moved = passed.perform_move(passed.possible_moves[0])
+        self.assertEqual(moved.perform_null_move().history, [passed.possible_moves[0]])
+        self.assertEqual(moved.perform_null_move().undo().undo().history, [])
+
+    def test_undo(self):
+        state = create_movement_state()
+        slide = state.perform_move(Move(from_value=HexCoordinate(10, 2), to_value=HexCoordinate(8, 2)))
+        self.assertEqual(slide.undo(), state)
+        self.assertEqual(slide.undo().board[HexCoordinate(8, 2)], 3)
+
+        reply = slide.perform_move(slide.possible_moves[0])
+        self.assertEqual(reply.undo(), slide)
+        self.assertEqual(reply.undo().undo(), state)
+        with self.assertRaises(ValueError):
+            reply.undo().undo().undo()
+
+        placement = GameState(board=create_board([[1] * 8 for _ in range(8)]), turn=0, start_team=Team("ONE"),
+                              fishes=Fishes(0, 0))
+        placed = placement.perform_move(Move(to_value=HexCoordinate(0, 0)))
+        self.assertEqual(placed.undo(), placement)
+        self.assertEqual(placed.undo().fishes, Fishes(0, 0))
+
+    def test_last_move_fish_gain(self):
+        state = create_movement_state()
+        self.assertIsNone(state.last_move_fish_gain())
+        slide = state.perform_move(Move(from_value=HexCoordinate(10, 2), to_value=HexCoordinate(8, 2)))
+        self.assertEqual(slide.last_move_fish_gain(), 3)
+        self.assertEqual(slide.last_move_fish_gain(), slide.fishes.fishes_one - state.fishes.fishes_one)
+
+        received = GameState(board=slide.board, turn=slide.turn, start_team=slide.start_team, fishes=slide.fishes,
+                             last_move=slide.last_move)
+        self.assertIsNone(received.last_move_fish_gain())
+
+        placement = GameState(board=create_board([[1] * 8 for _ in range(8)]), turn=0, start_team=Team("ONE"),
+                              fishes=Fishes(0, 0))
+        self.assertIsNone(placement.last_move_fish_gain())
+        self.assertEqual(placement.perform_move(Move(to_value=HexCoordinate(0, 0))).last_move_fish_gain(), 0)
+        self.assertEqual(self.g.last_move_fish_gain(), 0)
+
+    def test_ordering(self):
+        state = create_movement_state()
+        ahead = GameState(board=state.board, turn=8, start_team=Team("ONE"), fishes=Fishes(6, 4))
+        behind = GameState(board=state.board, turn=8, start_team=Team("ONE"), fishes=Fishes(4, 6))
+        later = state.perform_move(Move(from_value=HexCoordinate(10, 2), to_value=HexCoordinate(8, 2)))
+        self.assertLess(behind, state)
+        self.assertLess(state, ahead)
+        self.assertGreater(later, ahead)
+        self.assertFalse(state < state or state > state)
+        self.assertTrue(state <= state and state >= state)
+        self.assertLessEqual(behind, state)
+        self.assertGreaterEqual(later, ahead)
+        self.assertFalse(later <= ahead or behind >= state)
+        self.assertEqual(sorted([later, ahead, state, behind]), [behind, state, ahead, later])
+
+        frontier = []
+        for game_state in [later, behind, ahead, state]:
+            heapq.heappush(frontier, game_state)
+        self.assertEqual([heapq.heappop(frontier) for _ in range(4)], [behind, state, ahead, later])
+        with self.assertRaises(TypeError):
+            _ = state < 8
+
+    def test_to_feature_array(self):
+        state = create_movement_state(turn=9)
+        self.assertEqual(state.current_team, Team("TWO"))
+        planes = state.to_feature_array()
+        self.assertEqual(len(planes), 7)
+        self.assertTrue(all(len(plane) == 8 and all(len(row) == 8 for row in plane) for plane in planes))
+        self.assertEqual(sum(map(sum, planes[0])), 4)
+        self.assertEqual(planes[0][0][6], 1.0)
+        self.assertEqual(planes[1][0][0], 1.0)
+        for fish in range(1, 5):
+            self.assertEqual(sum(map(sum, planes[1 + fish])), len(state.board.fields_with_fish(fish)))
+        self.assertEqual(sum(map(sum, planes[6])), 0)
+        self.assertEqual(sum(map(sum, create_movement_state().to_feature_array()[6])), 64)
+
+    def test_zobrist_hash(self):
+        state = GameState(board=create_board([[1] * 8 for _ in range(8)]), turn=0, start_team=Team("ONE"),
+                          fishes=Fishes(0, 0))
+        state.zobrist_hash()
+        first, second, third = HexCoordinate(0, 0), HexCoordinate(7, 3), HexCoordinate(14, 6)
+        one = state.perform_move(Move(to_value=first)).perform_move(Move(to_value=second))
+        one = one.perform_move(Move(to_value=third))
+        other = state.perform_move(Move(to_value=third)).perform_move(Move(to_value=second))
+        other = other.perform_move(Move(to_value=first))
+        self.assertNotEqual(one.last_move, other.last_move)
+        self.assertEqual(one.zobrist_hash(), other.zobrist_hash())
+        fresh = GameState(board=one.board, turn=one.turn, start_team=one.start_team, fishes=one.fishes)
+        self.assertEqual(fresh.zobrist_hash(), one.zobrist_hash())
+
+        hashes = {one.zobrist_hash()}
+        for move in one.possible_moves:
+            hashes.add(one.perform_move(move).zobrist_hash())
+        self.assertEqual(len(hashes), len(one.possible_moves) + 1)
+        other_side = GameState(board=one.board, turn=one.turn + 1, start_team=one.start_team, fishes=one.fishes)
+        self.assertNotEqual(other_side.zobrist_hash(), one.zobrist_hash())
+
+        state = create_movement_state()
+        state.zobrist_hash()
+        for move in state.possible_moves:
+            new_state = state.perform_move(move)
+            fresh = GameState(board=new_state.board, turn=new_state.turn, start_team=new_state.start_team,
+                              fishes=new_state.fishes)
+            self.assertEqual(new_state.zobrist_hash(), fresh.zobrist_hash())
+
+    def test_to_json(self):
+        move = Move(from_value=HexCoordinate(10, 2), to_value=HexCoordinate(8, 2))
+        state = create_movement_state().perform_move(move)
+        with open(os.path.join(os.path.dirname(__file__), "fixtures", "game_state.json")) as fixture:
+            self.assertEqual(state.to_json() + "\n", fixture.read())
+        self.assertEqual(json.loads(state.to_json()), state.to_dict())
+        self.assertIsNone(create_movement_state().to_dict()["last_move"])
+        self.assertIsNone(Move(to_value=HexCoordinate(3, 1)).to_dict()["from"])
+
+    def test_equal_ignoring_history(self):
+        state = GameState(board=create_board([[1] * 8 for _ in range(8)]), turn=0, start_team=Team("ONE"),
+                          fishes=Fishes(0, 0))
+        first, second, third = HexCoordinate(0, 0), HexCoordinate(7, 3), HexCoordinate(14, 6)
+        one = state.perform_move(Move(to_value=first)).perform_move(Move(to_value=second))
+        one = one.perform_move(Move(to_value=third))
+        other = state.perform_move(Move(to_value=third)).perform_move(Move(to_value=second))
+        other = other.perform_move(Move(to_value=first))
+        self.assertNotEqual(one, other)
+        self.assertTrue(one.equal_ignoring_history(other))
+        self.assertTrue(one.equal_ignoring_history(one.clone_with_board(one.board)))
+        self.assertFalse(one.equal_ignoring_history(one.perform_move(one.possible_moves[0])))
+        self.assertFalse(one.equal_ignoring_history(other.clone_with_board(state.board)))
+
+    def test_to_bytes(self):
+        movement = create_movement_state()
+        for state in [movement, movement.perform_move(movement.possible_moves[0]),
+                      GameState(board=create_board([[1, 2], [0, 1]]), turn=0, start_team=Team("TWO"),
+                                fishes=Fishes(0, 0))]:
+            data = state.to_bytes()
+            self.assertEqual(len(data), 13 + state.board.width() * state.board.height())
+            self.assertEqual(GameState.from_bytes(data), state)
+            self.assertEqual(GameState.from_bytes(data).current_team, state.current_team)
+        placed = GameState(board=create_board([["ONE", 2], [0, 1]]), turn=1, start_team=Team("ONE"),
+                           fishes=Fishes(1, 0), last_move=Move(to_value=HexCoordinate(0, 0)))
+        self.assertEqual(GameState.from_bytes(placed.to_bytes()).last_move, placed.last_move)
+        self.assertLess(len(create_movement_state().to_bytes()), len(create_movement_state().to_json()))
+
+        data = create_movement_state().to_bytes()
+        for truncated in [data[:-1], data[:5], b""]:
+            with self.assertRaises(ValueError):
+                GameState.from_bytes(truncated)
+        with self.assertRaises(ValueError):
+            GameState.from_bytes(data[:-1] + bytes([7]))
+
+
+class BatchTest(unittest.TestCase):
+    def test_batch_possible_moves(self):
+        two_starts = GameState(board=create_board(movement_fields), turn=8, start_team=Team("TWO"), fishes=Fishes(4, 4))
+        states = [create_movement_state(), create_movement_state(turn=9), two_starts]
+        self.assertEqual(batch_possible_moves(states), [state._get_possible_moves(state.current_team)
+                                                        for state in states])
+        self.assertEqual(batch_possible_moves([]), [])
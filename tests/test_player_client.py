@@ -0,0 +1,192 @@
+import os
+import tempfile
+import unittest
+
+from socha.api.networking.player_client import _PlayerClient, IClientHandler, _dry_run
+from socha.api.plugin.penguins import *
+from socha.api.protocol.protocol import Result
+from tests.test_penguins import create_movement_state
+from tests.test_xflux import board_rows, compact, create_memento, memento, result, welcome_message
+
+
+class CountingLogic(IClientHandler):
+    def __init__(self):
+        self.updates = 0
+        self.requests = 0
+        self.game_state = None
+
+    def calculate_move(self) -> Move:
+        self.requests += 1
+        return self.game_state.possible_moves[0]
+
+    def on_update(self, state: GameState):
+        self.updates += 1
+        self.game_state = state
+
+
+class ResultLogic(IClientHandler):
+    def __init__(self):
+        self.results = []
+
+    def on_game_over(self, roomMessage: Result):
+        self.results.append(roomMessage)
+
+
+class FixedLogic(IClientHandler):
+    def __init__(self, move: Move):
+        self.move = move
+
+    def calculate_move(self) -> Move:
+        return self.move
+
+
+move_request = b'<room roomId="b5a4f8c2"><data class="moveRequest"/></room>'
+
+
+def write_transcript(frames: List[bytes]) -> str:
+    """
+    Writes the frames to a temporary protocol log and returns its path.
+    """
+    file, path = tempfile.mkstemp(suffix=".xml")
+    with os.fdopen(file, "wb") as transcript:
+        transcript.write(b"<protocol>\n" + b"\n".join(frames) + b"\n")
+    return path
+
+
+class ReplayTest(unittest.TestCase):
+    def test_replay(self):
+        path = write_transcript([b'<joined roomId="b5a4f8c2"/>', memento, move_request, memento, move_request,
+                                 memento, b'<left roomId="b5a4f8c2"/>'])
+        self.addCleanup(os.remove, path)
+
+        logic = CountingLogic()
+        client = _PlayerClient(host="localhost", port=13050, handler=logic, survive=False, replay_file=path)
+        with self.assertRaises(SystemExit):
+            client.start()
+        self.assertEqual(logic.updates, 3)
+        self.assertEqual(logic.requests, 2)
+        self.assertFalse(client._network_interface.connected)
+
+    def test_replay_without_left(self):
+        path = write_transcript([b'<joined roomId="b5a4f8c2"/>', memento])
+        self.addCleanup(os.remove, path)
+
+        logic = CountingLogic()
+        client = _PlayerClient(host="localhost", port=13050, handler=logic, survive=False, replay_file=path)
+        with self.assertRaises(SystemExit):
+            client.start()
+        self.assertEqual(logic.updates, 1)
+
+    def test_requested_color_mismatch(self):
+        path = write_transcript([b'<joined roomId="b5a4f8c2"/>', welcome_message, memento, move_request,
+                                 b'<left roomId="b5a4f8c2"/>'])
+        self.addCleanup(os.remove, path)
+
+        logic = CountingLogic()
+        client = _PlayerClient(host="localhost", port=13050, handler=logic, survive=False, replay_file=path,
+                               requested_color=Team("TWO"))
+        with self.assertLogs(level="WARNING") as logs:
+            with self.assertRaises(SystemExit):
+                client.start()
+        self.assertIn("assigned team ONE", "\n".join(logs.output))
+        self.assertEqual(client.team, Team("ONE"))
+        self.assertEqual(logic.requests, 1)
+
+    def test_opponent_blocked(self):
+        rows = [["ONE", "ONE", "ONE", "ONE", 0, 1, 2, 3], [0, 0, 0, 0, 0, 1, 2, 3]] + [[1] * 8 for _ in range(5)] + \
+               [["TWO", "TWO", "TWO", "TWO", 1, 1, 1, 1]]
+        path = write_transcript([b'<joined roomId="b5a4f8c2"/>', welcome_message.replace(b"ONE", b"TWO"),
+                                 create_memento(rows, turn=8), move_request, b'<left roomId="b5a4f8c2"/>'])
+        self.addCleanup(os.remove, path)
+
+        logic = CountingLogic()
+        client = _PlayerClient(host="localhost", port=13050, handler=logic, survive=False, replay_file=path)
+        sent = []
+        client._network_interface.send = sent.append
+        with self.assertRaises(SystemExit):
+            client.start()
+        self.assertEqual(client.game_state.current_team, Team("TWO"))
+        self.assertEqual(logic.requests, 1)
+        self.assertIn(logic.game_state.possible_moves[0].to_xml("b5a4f8c2").encode("utf-8"), compact(b"".join(sent)))
+
+    def test_blocked_out_of_sync(self):
+        rows = [["ONE", "ONE", "ONE", "ONE", 0, 1, 2, 3], [0, 0, 0, 0, 0, 1, 2, 3]] + [[1] * 8 for _ in range(5)] + \
+               [["TWO", "TWO", "TWO", "TWO", 1, 1, 1, 1]]
+        path = write_transcript([b'<joined roomId="b5a4f8c2"/>', welcome_message, create_memento(rows, turn=8),
+                                 move_request, b'<left roomId="b5a4f8c2"/>'])
+        self.addCleanup(os.remove, path)
+
+        logic = CountingLogic()
+        client = _PlayerClient(host="localhost", port=13050, handler=logic, survive=False, replay_file=path)
+        with self.assertLogs(level="ERROR") as logs:
+            with self.assertRaises(SystemExit):
+                client.start()
+        self.assertIn("move of team ONE, which has no possible moves in the last received state of turn 8",
+                      "\n".join(logs.output))
+        self.assertIn("out of sync", "\n".join(logs.output))
+        self.assertEqual(logic.updates, 1)
+        self.assertEqual(logic.requests, 0)
+
+    def test_joined_room(self):
+        path = write_transcript([b'<joined roomId="c3d2e1f0"/>', memento.replace(b"b5a4f8c2", b"c3d2e1f0"),
+                                 move_request.replace(b"b5a4f8c2", b"c3d2e1f0"), b'<left roomId="c3d2e1f0"/>'])
+        self.addCleanup(os.remove, path)
+
+        logic = CountingLogic()
+        client = _PlayerClient(host="localhost", port=13050, handler=logic, survive=False, replay_file=path)
+        sent = []
+        client._network_interface.send = sent.append
+        with self.assertRaises(SystemExit):
+            client.start()
+        self.assertEqual(client.room_id, "c3d2e1f0")
+        self.assertEqual(logic.requests, 1)
+        self.assertIn(b'<room roomId="c3d2e1f0">', sent[0])
+        self.assertIn(b'<data class="move">', sent[0])
+
+    def test_strict(self):
+        rows = [row[:] for row in board_rows]
+        rows[0][0] = "ONE"
+        placed = create_memento(rows, turn=3, last_move='<to x="0" y="0"/>', fishes=(2, 1))
+        divergent = create_memento(rows, turn=3, last_move='<to x="0" y="0"/>', fishes=(1, 1))
+        for second, warnings in [(placed, []), (divergent, ["The fish of turn 3 from the server diverge"])]:
+            path = write_transcript([b'<joined roomId="b5a4f8c2"/>', memento, second, b'<left roomId="b5a4f8c2"/>'])
+            self.addCleanup(os.remove, path)
+
+            client = _PlayerClient(host="localhost", port=13050, handler=CountingLogic(), survive=False,
+                                   replay_file=path, strict=True)
+            with self.assertLogs(level="INFO") as logs:
+                with self.assertRaises(SystemExit):
+                    client.start()
+            output = [line for line in logs.output if line.startswith("WARNING")]
+            self.assertEqual(len(output), len(warnings))
+            for line, warning in zip(output, warnings):
+                self.assertIn(warning, line)
+
+    def test_game_over(self):
+        path = write_transcript([b'<joined roomId="b5a4f8c2"/>', memento, result, b'<left roomId="b5a4f8c2"/>'])
+        self.addCleanup(os.remove, path)
+
+        logic = ResultLogic()
+        client = _PlayerClient(host="localhost", port=13050, handler=logic, survive=False, replay_file=path)
+        with self.assertRaises(SystemExit):
+            client.start()
+        self.assertEqual(len(logic.results), 1)
+        self.assertEqual(logic.results[0].get_winner(), Team("ONE"))
+        self.assertEqual(logic.results[0].get_cause(), "LEFT")
+
+
+class DryRunTest(unittest.TestCase):
+    def test_valid_move(self):
+        state = create_movement_state()
+        logic = CountingLogic()
+        self.assertEqual(_dry_run(logic, state), state.possible_moves[0])
+        self.assertEqual(logic.updates, 1)
+        self.assertEqual(logic.requests, 1)
+
+    def test_invalid_move(self):
+        state = create_movement_state()
+        invalid_move = Move(from_value=HexCoordinate(0, 0), to_value=HexCoordinate(15, 7))
+        with self.assertRaisesRegex(ValueError, "invalid move"):
+            _dry_run(FixedLogic(invalid_move), state)
+        with self.assertRaisesRegex(ValueError, "invalid move"):
+            _dry_run(FixedLogic(None), state)
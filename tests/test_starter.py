@@ -0,0 +1,81 @@
+import json
+import os
+import tempfile
+import unittest
+from unittest.mock import patch
+
+from socha.api.networking.player_client import IClientHandler
+from socha.starter import Starter, SochaConnectionError
+from tests.mock_server import MockServer
+from tests.test_xflux import result
+
+
+@patch("sys.argv", ["logic.py"])
+class AddressTest(unittest.TestCase):
+    def test_invalid_port(self):
+        for port in [0, -1, 65536]:
+            with self.assertRaises(SochaConnectionError) as context:
+                Starter(IClientHandler(), "localhost", port)
+            self.assertIn(f"localhost:{port}", str(context.exception))
+
+    def test_unresolvable_host(self):
+        with self.assertRaises(SochaConnectionError) as context:
+            Starter(IClientHandler(), "server.invalid", 13050)
+        self.assertIn("server.invalid:13050", str(context.exception))
+        self.assertIn("can't be resolved", str(context.exception))
+
+
+@patch("sys.argv", ["logic.py"])
+class ResumeTest(unittest.TestCase):
+    def test_crash_and_resume(self):
+        directory = tempfile.TemporaryDirectory()
+        self.addCleanup(directory.cleanup)
+        path = os.path.join(directory.name, "resume.json")
+
+        server = MockServer([b'<protocol><joined roomId="c3d2e1f0"/>', b'<left roomId="c3d2e1f0"/>'])
+        with self.assertRaises(SystemExit):
+            Starter(IClientHandler(), "localhost", server.port, resume_token_path=path)
+        server.join()
+        self.assertIn(b"<join", server.received)
+        with open(path) as file:
+            self.assertEqual(json.load(file)["room_id"], "c3d2e1f0")
+
+        server = MockServer([b'<protocol><joined roomId="c3d2e1f0"/>', result.replace(b"b5a4f8c2", b"c3d2e1f0"),
+                             b'<left roomId="c3d2e1f0"/>'])
+        with self.assertRaises(SystemExit):
+            Starter(IClientHandler(), "localhost", server.port, resume_token_path=path)
+        server.join()
+        self.assertIn(b'<joinRoom roomId="c3d2e1f0"/>', server.received)
+        self.assertFalse(os.path.exists(path))
+
+    def test_resume_dead_room(self):
+        directory = tempfile.TemporaryDirectory()
+        self.addCleanup(directory.cleanup)
+        path = os.path.join(directory.name, "resume.json")
+        with open(path, "w") as file:
+            json.dump({"room_id": "c3d2e1f0"}, file)
+
+        server = MockServer([b'<protocol><left roomId="c3d2e1f0"/>', b'<joined roomId="d4e3f2a1"/>',
+                             b'<left roomId="d4e3f2a1"/>'])
+        with self.assertRaises(SystemExit):
+            Starter(IClientHandler(), "localhost", server.port, resume_token_path=path)
+        server.join()
+        self.assertIn(b'<joinRoom roomId="c3d2e1f0"/>', server.received)
+        self.assertIn(b"<join/>", server.received)
+        with open(path) as file:
+            self.assertEqual(json.load(file), {"room_id": "d4e3f2a1"})
+
+    def test_arguments_before_resume_token(self):
+        directory = tempfile.TemporaryDirectory()
+        self.addCleanup(directory.cleanup)
+        path = os.path.join(directory.name, "resume.json")
+        with open(path, "w") as file:
+            json.dump({"room_id": "c3d2e1f0"}, file)
+
+        server = MockServer([b'<protocol><left roomId="c3d2e1f0"/>'])
+        with patch("sys.argv", ["logic.py", "--reservation", "a1b2c3"]):
+            with self.assertRaises(SystemExit):
+                Starter(IClientHandler(), "localhost", server.port, resume_token_path=path)
+        server.join()
+        self.assertIn(b'<joinPrepared reservationCode="a1b2c3"/>', server.received)
+        self.assertNotIn(b"<joinRoom", server.received)
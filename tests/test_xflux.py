@@ -0,0 +1,163 @@
+import unittest
+
+from socha.api.networking._xflux import _XFlux, _convertMoveToData
+from socha.api.networking.player_client import _convertState
+from socha.api.plugin.penguins import *
+from socha.api.protocol.protocol import Result, Room, State, WelcomeMessage
+
+welcome_message = b'<room roomId="b5a4f8c2"><data class="welcomeMessage" color="ONE"/></room>'
+
+board_rows = [
+    [1, 3, 0, 2, 1, 4, 2, 1],
+    [2, "ONE", 1, 3, 2, 1, 0, 2],
+    [3, 1, 2, 4, 1, 2, 3, 1],
+    [0, 2, 1, 1, 3, 2, 1, 4],
+    [4, 1, 2, 3, 1, 1, 2, 0],
+    [1, 3, 2, 1, 4, 2, 1, 3],
+    [2, 0, 1, 2, 3, 1, "TWO", 2],
+    [1, 2, 4, 1, 2, 0, 3, 1],
+]
+
+
+def create_memento(rows: List[List[Union[int, str]]], turn: int = 2, last_move: str = '<to x="12" y="6"/>',
+                   fishes: Tuple[int, int] = (1, 1)) -> bytes:
+    """
+    Creates a memento of the server, that contains the board with the given rows.
+    """
+    return """<room roomId="b5a4f8c2">
+  <data class="memento">
+    <state class="state" turn="{}">
+      <startTeam>ONE</startTeam>
+      <board>
+{}
+      </board>
+      <lastMove>
+        {}
+      </lastMove>
+      <fishes>
+        <int>{}</int>
+        <int>{}</int>
+      </fishes>
+    </state>
+  </data>
+</room>""".format(turn, "\n".join("        <list>" + "".join(f"<field>{field}</field>" for field in row) + "</list>"
+                                  for row in rows), last_move, *fishes).encode("utf-8")
+
+
+memento = create_memento(board_rows)
+
+result = b"""<room roomId="b5a4f8c2">
+  <data class="result">
+    <definition>
+      <fragment name="Siegpunkte">
+        <aggregation>SUM</aggregation>
+        <relevantForRanking>true</relevantForRanking>
+      </fragment>
+      <fragment name="Fische">
+        <aggregation>AVERAGE</aggregation>
+        <relevantForRanking>true</relevantForRanking>
+      </fragment>
+    </definition>
+    <scores>
+      <entry>
+        <player name="one" team="ONE"/>
+        <score cause="REGULAR" reason="">
+          <part>2</part>
+          <part>27</part>
+        </score>
+      </entry>
+      <entry>
+        <player name="two" team="TWO"/>
+        <score cause="LEFT" reason="The player left the game.">
+          <part>0</part>
+          <part>15</part>
+        </score>
+      </entry>
+    </scores>
+    <winner team="ONE"/>
+  </data>
+</room>"""
+
+
+class ParserTest(unittest.TestCase):
+    x_flux = _XFlux()
+
+    def test_welcome_message(self):
+        room = self.x_flux.deserialize_object(welcome_message)
+        self.assertIsInstance(room, Room)
+        self.assertEqual(room.room_id, "b5a4f8c2")
+        self.assertIsInstance(room.data.class_binding, WelcomeMessage)
+        self.assertEqual(room.data.class_binding.team, Team("ONE"))
+
+    def test_memento(self):
+        room = self.x_flux.deserialize_object(memento)
+        self.assertIsInstance(room.data.class_binding, State)
+
+        game_state = _convertState(room.data.class_binding)
+        self.assertEqual(game_state.turn, 2)
+        self.assertEqual(game_state.round, 1)
+        self.assertEqual(game_state.start_team, Team("ONE"))
+        self.assertEqual(game_state.current_team, Team("ONE"))
+        self.assertEqual(game_state.fishes.fishes_one, 1)
+        self.assertEqual(game_state.fishes.fishes_two, 1)
+        self.assertIsNone(game_state.last_move.from_value)
+        self.assertEqual(game_state.last_move.to_value, HexCoordinate(12, 6))
+
+        self.assertEqual(game_state.board.get_field(HexCoordinate(0, 0)).field, 1)
+        self.assertEqual(game_state.board.get_field(HexCoordinate(3, 1)).field, Team("ONE"))
+        self.assertEqual(game_state.board.get_field(HexCoordinate(12, 6)).field, Team("TWO"))
+        self.assertEqual(game_state.board.get_field(HexCoordinate(11, 7)).field, 0)
+        self.assertEqual(game_state.board.get_teams_penguins(Team("ONE")), [HexCoordinate(3, 1)])
+        self.assertEqual(game_state.board.get_teams_penguins(Team("TWO")), [HexCoordinate(12, 6)])
+
+    def test_result(self):
+        room = self.x_flux.deserialize_object(result)
+        self.assertIsInstance(room.data.class_binding, Result)
+
+        game_result = room.data.class_binding
+        self.assertEqual(game_result.get_winner(), Team("ONE"))
+        self.assertEqual(game_result.get_fishes(Team("ONE")), 27)
+        self.assertEqual(game_result.get_fishes(Team("TWO")), 15)
+        self.assertEqual(game_result.get_score(Team("TWO")).reason, "The player left the game.")
+        self.assertEqual(game_result.get_cause(), "LEFT")
+
+        draw = Result(definition=game_result.definition, scores=game_result.scores, winner=None)
+        self.assertIsNone(draw.get_winner())
+
+
+def compact(xml: bytes) -> bytes:
+    """
+    Removes the indentation of pretty printed xml.
+    """
+    return b"".join(line.strip() for line in xml.splitlines())
+
+
+class SerializerTest(unittest.TestCase):
+    x_flux = _XFlux()
+
+    def test_placement(self):
+        move = Move(to_value=HexCoordinate(13, 5))
+        self.assertEqual(compact(self.x_flux.serialize_object(move)),
+                         b'<data class="move"><to x="13" y="5"/></data>')
+
+    def test_slide(self):
+        move = Move(from_value=HexCoordinate(3, 1), to_value=HexCoordinate(7, 1))
+        self.assertEqual(compact(self.x_flux.serialize_object(move)),
+                         b'<data class="move"><from x="3" y="1"/><to x="7" y="1"/></data>')
+
+    def test_room(self):
+        room = Room(room_id="b5a4f8c2", data=_convertMoveToData(Move(to_value=HexCoordinate(0, 0))))
+        self.assertEqual(compact(self.x_flux.serialize_object(room)),
+                         b'<room roomId="b5a4f8c2"><data class="move"><to x="0" y="0"/></data></room>')
+
+    def test_to_xml(self):
+        placement = Move(to_value=HexCoordinate(13, 5))
+        slide = Move(from_value=HexCoordinate(3, 1), to_value=HexCoordinate(7, 1))
+        self.assertEqual(placement.to_xml(), '<data class="move"><to x="13" y="5"/></data>')
+        self.assertEqual(slide.to_xml(), '<data class="move"><from x="3" y="1"/><to x="7" y="1"/></data>')
+        self.assertEqual(slide.to_xml("b5a4f8c2"), '<room roomId="b5a4f8c2"><data class="move">'
+                                                   '<from x="3" y="1"/><to x="7" y="1"/></data></room>')
+        for move in [placement, slide]:
+            self.assertEqual(move.to_xml().encode("utf-8"), compact(self.x_flux.serialize_object(move)))
+            room = Room(room_id="b5a4f8c2", data=_convertMoveToData(move))
+            self.assertEqual(move.to_xml("b5a4f8c2").encode("utf-8"), compact(self.x_flux.serialize_object(room)))